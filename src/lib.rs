@@ -1,25 +1,49 @@
 #![forbid(unsafe_code)]
+#![no_std]
 //! Macro for creating an enum where all variants have an associated constant string.
 //! Syntax:
 //! ```
 //! str_enum::str_enum! {
+//!     /// Doc comments are kept on the enum
 //!     #[phf] // Adds a PHF map
 //!     #[error_type(MyErrorType)] // Add this to opt-in to a FromStr implementation
 //!     #[derive(Clone, Copy)] // You can add derives (exceptions: de/serialize enable the `serde` feature for that, Hash which is implemented automatically to be compatible with &str since the type is Borrow<str>)
 //!     #[repr(u8)]
+//!     #[serde(compact)] // With the `serde` feature, non human-readable formats (bincode, postcard, etc.) use the discriminant instead of the string
+//!     #[lasso(MyEnumKeys)] // With the `lasso` feature, adds `MyEnum::seed_interner` which interns all values and returns them as a `MyEnumKeys`
+//!     #[parse(case_insensitive)] // Inputs that match nothing exactly are compared again ignoring ASCII case (`case_fold` does full Unicode case folding with the `caseless` feature)
+//!     #[str_type(MyEnumStr)] // Adds a `MyEnumStr` newtype over a `&'static str` that is always one of the values
+//!     #[rename_all = "PascalCase"] // Variants that leave out `=> "..."` take their name as the value, this converts it with serde's rule of the same name
+//!     #[catch_all(MaybeMyEnum)] // Adds a `MaybeMyEnum` that is either `Known(MyEnum)` or `Other(String)`, for inputs that may hold values this enum doesn't know yet
+//!     #[must_use] // Other attributes are put on the enum as written. All of these can go in any order
 //!     pub enum MyEnum {
-//!         Variant0 => "Value0"("other valid forms such as", "value0", "can go in brackets"), // note these other valid forms are only used in the enum's try_from_str method and FromStr implementation.
-//!         Variant1 = 3 => "Value1" // you can add a discriminant
+//!         Variant0 => "Value0"("other valid forms such as", "value0", "can go in brackets"), // note these other valid forms are only used when parsing, via try_from_str, FromStr and serde.
+//!         Variant2 => "Value2"(parse "v2", serde "value_2", deprecated "val2"), // other valid forms can be limited to try_from_str/FromStr (`parse`) or deserializing (`serde`), or marked `deprecated` to log a warning with the `log` feature
+//!         /// Doc comments go on the variant, and `documentation()` returns them
+//!         Variant1 = 3 => "Value1" ? "The first value", // you can add a discriminant, and a description after `?` that `description()` returns
+//!         #[parse(skip)] Sentinel => "Sentinel", // still a variant with a value, but never produced by parsing or deserializing
+//!         #[parse(fallback)] Unknown => "Unknown", // parsing and deserializing input that matches no other value gives this instead of an error
+//!         // Variant4, // leaving out `=> "..."` makes the value the variant name, "Variant4", but not with `#[phf]` which needs every value written out
 //!     }
 //! }
 //!
 //! let var0 = MyEnum::try_from_str("value0").unwrap();
-//! assert_eq!(var0.as_str(), MyEnum::Variant0.as_str())
+//! assert_eq!(var0.as_str(), MyEnum::Variant0.as_str());
+//!
+//! const VAR2: Option<MyEnum> = MyEnum::try_from_str_const("v2"); // a slower `try_from_str` that works in const contexts
+//! assert_eq!(VAR2.unwrap().as_str(), "Value2")
 //! ```
 //!
 //! Note, due to how we assemble some strings at compile time you'll see some constants that you likely never need to interact with.
 //! You can just throw the enum in its own module to avoid seeing them since they're private visibility.
 
+#[doc(hidden)]
+pub extern crate alloc;
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub extern crate std;
+
 #[cfg(feature = "serde")]
 pub use serde;
 
@@ -29,725 +53,4758 @@ pub use strum;
 #[cfg(feature = "phf")]
 pub use phf;
 
-#[cfg(feature = "phf")]
+#[cfg(feature = "lasso")]
+pub use lasso;
+
+#[cfg(feature = "memchr")]
+pub use memchr;
+
+#[cfg(feature = "caseless")]
+pub use caseless;
+
+#[cfg(feature = "log")]
+pub use log;
+
+#[cfg(feature = "miette")]
+pub use miette;
+
+#[cfg(feature = "schemars")]
+pub use schemars;
+
+#[cfg(feature = "utoipa")]
+pub use utoipa;
+
+#[cfg(feature = "clap")]
+pub use clap;
+
+#[cfg(feature = "rocket")]
+pub use rocket;
+
+#[cfg(feature = "async-graphql")]
+pub use async_graphql;
+
+#[cfg(feature = "juniper")]
+pub use juniper;
+
+#[cfg(feature = "http")]
+pub use http;
+
+#[cfg(feature = "sqlx")]
+pub use sqlx;
+
+#[cfg(feature = "derive")]
+pub use str_enum_derive::StrEnum;
+
+// the parsing methods of both lookups, `$exact` finding the value or alternate form `$s` spells exactly
 #[macro_export]
-macro_rules! str_enum_try_from_str {
-    (#[phf] $(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
+macro_rules! str_enum_parse_fns {
+    ($ty:ident, $try_from_str_doc:literal, |$s:ident, $scope:ident| $exact:expr, $parse_opts:tt, $variants:tt) => {
+        #[allow(deprecated)]
         impl $ty {
-            #[doc = "Compile time generated map from all valid string variants to their Variant"]
-            const PHF_MAP: $crate::phf::Map<&'static str, $ty> = $crate::phf::phf_map! {
-                $($val $($(| $other_valid )*)? => $ty::$variant,)*
-            };
-
-            #[doc = "Try to generate `Self` from an &str, using `Self::PHF_MAP`"]
+            #[doc = $try_from_str_doc]
             pub fn try_from_str(s: &str) -> Option<Self> {
-                Self::PHF_MAP.get(s).copied()
+                Self::try_from_str_scoped(s, $crate::AliasScope::Parse)
+            }
+
+            #[doc = "Like `Self::try_from_str`, but only accepting the alternate forms that are allowed in `scope`"]
+            pub fn try_from_str_scoped(s: &str, scope: $crate::AliasScope) -> Option<Self> {
+                Self::match_detailed_scoped(s, scope).map(|(variant, _)| variant).or_else(Self::fallback)
+            }
+
+            #[doc = "Like `Self::try_from_str`, but also returns the exact value or alternate form that matched"]
+            pub fn match_detailed(s: &str) -> Option<(Self, &'static str)> {
+                Self::match_detailed_scoped(s, $crate::AliasScope::Parse)
+            }
+
+            #[doc = "Like `Self::match_detailed`, but only accepting the alternate forms that are allowed in `scope`"]
+            pub fn match_detailed_scoped($s: &str, $scope: $crate::AliasScope) -> Option<(Self, &'static str)> {
+                $crate::str_enum_parse_opts!($s, $scope, $exact, $parse_opts, $variants)
+                    .filter(|(variant, _)| !variant.parse_skipped())
+                    .inspect(|(variant, _)| variant.warn_deprecated_variant($s))
             }
+
+            $crate::str_enum_warn_deprecated!($ty, $variants);
         }
     };
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
+}
+
+#[cfg(feature = "phf")]
+#[macro_export]
+macro_rules! str_enum_try_from_str {
+    (#[phf] $(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? => $val:literal $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        #[allow(deprecated)]
         impl $ty {
-            #[doc = "Try to generate `Self` from an &str by matching on all valid values.\nNote: If you have many variants you may want to enable the `phf` feature and add the `#[phf]` attribute."]
-            pub fn try_from_str(s: &str) -> Option<Self> {
-                match s {
-                    $($val $($(|$other_valid)*)? => Some(Self::$variant),)*
-                    _ => None,
-                }
-            }
+            #[doc = "Compile time generated map from the primary value of every variant to that variant.\nAlternate forms aren't in it, as one spelling may belong to different variants in different scopes; `Self::match_detailed_scoped` looks up values and alternate forms alike"]
+            pub const PHF_MAP: $crate::phf::Map<&'static str, $ty> = $crate::phf::phf_map! {
+                $($val => $ty::$variant,)*
+            };
         }
-    }
+
+        $crate::str_enum_parse_fns!($ty, "Try to generate `Self` from an &str, using `Self::PHF_MAP`", |s, scope| match $ty::PHF_MAP.get_entry(s) {
+            Some((spelling, variant)) => Some((*variant, *spelling)),
+            None => $crate::str_enum_lookup!(@aliases $ty, s, scope, { $($variant $(($($($alias_marker)* $other_valid),*))?,)* }),
+        }, [$($($parse_opt)*)?], { $($variant $(($($($alias_marker)* $other_valid),*))?,)* });
+    };
+    // phf_map! needs its keys as literals, which values derived from the variant name are not
+    (#[phf] $($rest:tt)*) => {
+        compile_error!("`#[phf]` needs an explicit `=> \"value\"` on every variant");
+    };
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $crate::str_enum_parse_fns!($ty, "Try to generate `Self` from an &str by matching on all valid values.\nNote: If you have many variants you may want to enable the `phf` feature and add the `#[phf]` attribute.", |s, scope| $crate::str_enum_lookup!($ty, s, scope, [$($($parse_opt)*)?], { $($variant $(($($($alias_marker)* $other_valid),*))?,)* }), [$($($parse_opt)*)?], { $($variant $(($($($alias_marker)* $other_valid),*))?,)* });
+    };
 }
 
 #[cfg(not(feature = "phf"))]
 #[macro_export]
 macro_rules! str_enum_try_from_str {
-    (#[phf] $(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
-        impl $ty {
-            #[doc = "Try to generate `Self` from an &str by matching on all valid values.\nNote: If you have many variants you may want to enable the `phf` feature and add the `#[phf]` attribute."]
-            pub fn try_from_str(s: &str) -> Option<Self> {
-                match s {
-                    $($val $($(|$other_valid)*)? => Some(Self::$variant),)*
-                    _ => None,
-                }
+    ($(#[phf])? $(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $crate::str_enum_parse_fns!($ty, "Try to generate `Self` from an &str by matching on all valid values.\nNote: If you have many variants you may want to enable the `phf` feature and add the `#[phf]` attribute.", |s, scope| $crate::str_enum_lookup!($ty, s, scope, [$($($parse_opt)*)?], { $($variant $(($($($alias_marker)* $other_valid),*))?,)* }), [$($($parse_opt)*)?], { $($variant $(($($($alias_marker)* $other_valid),*))?,)* });
+    };
+}
+
+// candidates are bucketed by length and then first byte, so a lookup compares a handful of lengths and at most a couple of strings
+#[macro_export]
+macro_rules! str_enum_match {
+    ($ty:ident, $s:ident, $scope:ident, $variants:tt) => {
+        $crate::str_enum_lookup!(@table $ty, $s, $scope, true, $variants)
+    };
+}
+
+#[macro_export]
+macro_rules! str_enum_lookup {
+    ($ty:ident, $s:ident, $scope:ident, [], $variants:tt) => {
+        $crate::str_enum_match!($ty, $s, $scope, $variants)
+    };
+    ($ty:ident, $s:ident, $scope:ident, [sorted $($rest:ident)*], $variants:tt) => {
+        $crate::str_enum_lookup!(@table $ty, $s, $scope, false, $variants)
+    };
+    ($ty:ident, $s:ident, $scope:ident, [$opt:ident $($rest:ident)*], $variants:tt) => {
+        $crate::str_enum_lookup!($ty, $s, $scope, [$($rest)*], $variants)
+    };
+    (@table $ty:ident, $s:ident, $scope:ident, $by_length:literal, { $($variant:ident $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))?),* $(,)? }) => {{
+        // (spelling, variant, scope it is accepted in, deprecated)
+        const SPELLINGS: &[(&str, fn() -> $ty, $crate::AliasScope, bool)] = &[
+            $(
+                ($ty::$variant.as_str(), || $ty::$variant, $crate::AliasScope::All, false),
+                $($(($other_valid, || $ty::$variant, $crate::str_enum_alias!(@scope $($alias_marker)*), $crate::str_enum_alias!(@deprecated $($alias_marker)*)),)*)?
+            )*
+        ];
+        const SORTED: [(&str, fn() -> $ty, $crate::AliasScope, bool); SPELLINGS.len()] = $crate::sort_spellings(SPELLINGS, $by_length);
+        $crate::find_sorted(&SORTED, $s, $scope, $by_length).map(|(spelling, variant, deprecated)| {
+            if deprecated {
+                Self::warn_deprecated_alias($s);
+            }
+            (variant(), spelling)
+        })
+    }};
+    // like `@table`, but only the alternate forms, for `#[phf]` whose map already holds the primary values
+    (@aliases $ty:ident, $s:ident, $scope:ident, { $($variant:ident $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))?),* $(,)? }) => {{
+        const ALIASES: &[(&str, fn() -> $ty, $crate::AliasScope, bool)] = &[
+            $($($(($other_valid, || $ty::$variant, $crate::str_enum_alias!(@scope $($alias_marker)*), $crate::str_enum_alias!(@deprecated $($alias_marker)*)),)*)?)*
+        ];
+        const SORTED: [(&str, fn() -> $ty, $crate::AliasScope, bool); ALIASES.len()] = $crate::sort_spellings(ALIASES, true);
+        $crate::find_sorted(&SORTED, $s, $scope, true).map(|(spelling, variant, deprecated)| {
+            if deprecated {
+                Self::warn_deprecated_alias($s);
+            }
+            (variant(), spelling)
+        })
+    }};
+}
+
+#[macro_export]
+macro_rules! str_enum_alias {
+    (@scope) => {
+        $crate::AliasScope::All
+    };
+    (@scope parse $($rest:ident)*) => {
+        $crate::AliasScope::Parse
+    };
+    (@scope serde $($rest:ident)*) => {
+        $crate::AliasScope::Serde
+    };
+    (@scope deprecated $($rest:ident)*) => {
+        $crate::str_enum_alias!(@scope $($rest)*)
+    };
+    (@scope display $($rest:ident)*) => {
+        $crate::str_enum_alias!(@scope $($rest)*)
+    };
+    (@scope $other:ident $($rest:ident)*) => {
+        compile_error!(concat!(
+            "unknown alias marker `",
+            stringify!($other),
+            "`, expected `parse`, `serde`, `deprecated` or `display`"
+        ))
+    };
+    (@deprecated) => {
+        false
+    };
+    (@deprecated deprecated $($rest:ident)*) => {
+        true
+    };
+    (@deprecated $other:ident $($rest:ident)*) => {
+        $crate::str_enum_alias!(@deprecated $($rest)*)
+    };
+    (@display) => {
+        false
+    };
+    (@display display $($rest:ident)*) => {
+        true
+    };
+    (@display $other:ident $($rest:ident)*) => {
+        $crate::str_enum_alias!(@display $($rest)*)
+    };
+    (@used [] $alias:literal) => {};
+    (@used [deprecated $($rest:ident)*] $alias:literal) => {
+        Self::warn_deprecated_alias($alias)
+    };
+    (@used [$other:ident $($rest:ident)*] $alias:literal) => {
+        $crate::str_enum_alias!(@used [$($rest)*] $alias)
+    };
+}
+
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! str_enum_warn_deprecated {
+    ($ty:ident, { $($variant:ident $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        #[doc = "Logs a warning the first time each deprecated alternate form is parsed. You do not need this."]
+        fn warn_deprecated_alias(alias: &str) {
+            match alias {
+                $($($(
+                    $other_valid if $crate::str_enum_alias!(@deprecated $($alias_marker)*) => {
+                        static WARNED: ::core::sync::atomic::AtomicBool = ::core::sync::atomic::AtomicBool::new(false);
+                        if !WARNED.swap(true, ::core::sync::atomic::Ordering::Relaxed) {
+                            $crate::log::warn!(
+                                "deprecated {} value {:?} was parsed, use {:?} instead",
+                                stringify!($ty),
+                                $other_valid,
+                                Self::$variant.as_str()
+                            );
+                        }
+                    }
+                )*)?)*
+                _ => {}
+            }
+        }
+
+        #[doc = "Logs a warning the first time each variant marked `#[deprecated]` is parsed. You do not need this."]
+        fn warn_deprecated_variant(&self, value: &str) {
+            static WARNED: [::core::sync::atomic::AtomicBool; $ty::NUM_VARIANTS] = [const { ::core::sync::atomic::AtomicBool::new(false) }; $ty::NUM_VARIANTS];
+            if self.is_deprecated() && !WARNED[self.index()].swap(true, ::core::sync::atomic::Ordering::Relaxed) {
+                $crate::log::warn!("deprecated {} value {:?} was parsed", stringify!($ty), value);
             }
         }
     };
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
-        impl $ty {
-            #[doc = "Try to generate `Self` from an &str by matching on all valid values.\nNote: If you have many variants you may want to enable the `phf` feature and add the `#[phf]` attribute."]
-            pub fn try_from_str(s: &str) -> Option<Self> {
-                match s {
-                    $($val $($(|$other_valid)*)? => Some(Self::$variant),)*
-                    _ => None,
-                }
+}
+
+#[cfg(not(feature = "log"))]
+#[macro_export]
+macro_rules! str_enum_warn_deprecated {
+    ($ty:ident, $variants:tt) => {
+        #[doc = "Logs a warning the first time each deprecated alternate form is parsed, when the `log` feature is enabled. You do not need this."]
+        fn warn_deprecated_alias(_alias: &str) {}
+
+        #[doc = "Logs a warning the first time each variant marked `#[deprecated]` is parsed, when the `log` feature is enabled. You do not need this."]
+        fn warn_deprecated_variant(&self, _value: &str) {}
+    };
+}
+
+#[macro_export]
+macro_rules! str_enum_parse_opts {
+    ($s:ident, $scope:ident, $exact:expr, [], $variants:tt) => {
+        $exact
+    };
+    ($s:ident, $scope:ident, $exact:expr, [case_fold $($rest:ident)*], $variants:tt) => {
+        $crate::str_enum_parse_opts!($s, $scope, $exact, [$($rest)*], $variants).or_else(|| $crate::str_enum_case_fold!($s, $scope, $variants))
+    };
+    ($s:ident, $scope:ident, $exact:expr, [case_insensitive $($rest:ident)*], $variants:tt) => {
+        $crate::str_enum_parse_opts!($s, $scope, $exact, [$($rest)*], $variants).or_else(|| {
+            let eq = |value: &str| $s.eq_ignore_ascii_case(value);
+            $crate::str_enum_match_by!(eq, $scope, $variants)
+        })
+    };
+    // the trimmed input goes through every other option again, it can't be trimmed further so this doesn't recurse twice
+    ($s:ident, $scope:ident, $exact:expr, [trim $($rest:ident)*], $variants:tt) => {
+        $crate::str_enum_parse_opts!($s, $scope, $exact, [$($rest)*], $variants).or_else(|| {
+            let trimmed = $s.trim();
+            if trimmed.len() == $s.len() {
+                None
+            } else {
+                Self::match_detailed_scoped(trimmed, $scope)
+            }
+        })
+    };
+    // only changes how the exact match is looked up, see `str_enum_lookup!`
+    ($s:ident, $scope:ident, $exact:expr, [sorted $($rest:ident)*], $variants:tt) => {
+        $crate::str_enum_parse_opts!($s, $scope, $exact, [$($rest)*], $variants)
+    };
+    ($s:ident, $scope:ident, $exact:expr, [$opt:ident $($rest:ident)*], $variants:tt) => {
+        compile_error!(concat!("unknown parse option `", stringify!($opt), "`, expected `case_fold`, `case_insensitive`, `sorted` or `trim`"))
+    };
+}
+
+#[macro_export]
+macro_rules! str_enum_match_by {
+    ($eq:ident, $scope:ident, { $($variant:ident $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $(
+            if $eq(Self::$variant.as_str()) {
+                Some((Self::$variant, Self::$variant.as_str()))
             }
+            $($(
+                else if $crate::str_enum_alias!(@scope $($alias_marker)*).allows($scope) && $eq($other_valid) {
+                    $crate::str_enum_alias!(@used [$($alias_marker)*] $other_valid);
+                    Some((Self::$variant, $other_valid))
+                }
+            )*)?
+            else
+        )* {
+            None
         }
-    }
+    };
+}
+
+#[cfg(feature = "caseless")]
+#[macro_export]
+macro_rules! str_enum_case_fold {
+    ($s:ident, $scope:ident, { $($variant:ident $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {{
+        let eq = |value: &str| {
+            $crate::caseless::Caseless::default_case_fold($s.chars()).eq($crate::caseless::Caseless::default_case_fold(value.chars()))
+        };
+        $crate::str_enum_match_by!(eq, $scope, { $($variant $(($($($alias_marker)* $other_valid),*))?,)* })
+    }};
+}
+
+#[cfg(not(feature = "caseless"))]
+#[macro_export]
+macro_rules! str_enum_case_fold {
+    ($s:ident, $scope:ident, $variants:tt) => {
+        compile_error!("`#[parse(case_fold)]` requires the `caseless` feature")
+    };
 }
 
 #[macro_export]
 macro_rules! str_enum_base {
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
+    (@define $(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $(#[attrs[$($attr:tt)*]])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $(
+            #[doc = $doc]
+        )*
         $(
             #[derive($($derive_trait,)*)]
         )?
         $(
             #[repr($repr)]
         )?
+        $($($attr)*)?
         $vis enum $ty {
             $(
+                $(#[doc = $variant_doc])*
+                $(#[deprecated $($variant_deprecated)*])?
                 $variant $(= $variant_repr)?,
             )*
         }
-
+    };
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        #[allow(deprecated)]
         impl $ty {
             #[doc = "Collection of all variants in `Self`"]
-            pub const ALL_VARIANTS: &[Self] = &[$(Self::$variant,)*];
+            pub const ALL_VARIANTS: &[Self] = &Self::VARIANT_ARRAY;
+            #[doc = "All variants in `Self` as an array, for when the length needs to be known at compile time"]
+            pub const VARIANT_ARRAY: [Self; Self::NUM_VARIANTS] = [$(Self::$variant,)*];
             #[doc = "Number of variants in `Self`"]
-            pub const NUM_VARIANTS: usize = Self::ALL_VARIANTS.len();
+            pub const NUM_VARIANTS: usize = Self::VARIANT_NAMES.len();
 
-            pub const fn as_str(&self) -> &'static str {
-                match self {
-                    $(Self::$variant => $val,)*
+            $crate::str_enum_base!(@as_str [$($rename_all)?] { $($variant $(=> $val)?,)* });
+
+            #[doc = "The doc comment on this variant, if it has one"]
+            pub const fn documentation(&self) -> Option<&'static str> {
+                match *self {
+                    $(Self::$variant => $crate::str_enum_base!(@documentation $($variant_doc)*),)*
+                }
+            }
+
+            #[doc = "The string `Display` writes: the first valid form marked `display`, or the value if there is none"]
+            pub const fn display_str(&self) -> &'static str {
+                match *self {
+                    $(Self::$variant => {
+                        $($(
+                            if $crate::str_enum_alias!(@display $($alias_marker)*) {
+                                return $other_valid;
+                            }
+                        )*)?
+                        self.as_str()
+                    })*
                 }
             }
 
             #[doc = "All values of `Self`, does not include alternate spellings used for `Self::try_from_str`"]
             pub const ALL_VALUES: &[&str] = &[$(Self::$variant.as_str(),)*];
 
-            #[doc = "Total length of `Self::ALL_VALUES + 1 byte separator. You do not need this."]
-            const ALL_VALUES_STR_LEN: usize = {
-                let mut len = 0usize;
-                let mut idx = 0usize;
+            #[doc = "The length in bytes of the longest value, not counting alternate spellings. 0 without variants"]
+            pub const MAX_STR_LEN: usize = {
+                let mut max = 0;
+                let mut idx = 0;
                 while idx < Self::ALL_VALUES.len() {
-                    let value = Self::ALL_VALUES[idx];
-                    len += value.len() + 1;
-                    idx += 1
+                    if Self::ALL_VALUES[idx].len() > max {
+                        max = Self::ALL_VALUES[idx].len();
+                    }
+                    idx += 1;
                 }
-                len - 1
+                max
             };
 
-            #[doc = "Fixed size byte array of `Self::ALL_VALUES` joined by a comma separator. You do not need this."]
-            const ALL_VALUE_BYTES: [u8; Self::ALL_VALUES_STR_LEN] = {
-                let mut buf = [0u8; Self::ALL_VALUES_STR_LEN];
+            #[doc = "The length in bytes of the shortest value, not counting alternate spellings. 0 without variants"]
+            pub const MIN_STR_LEN: usize = {
+                let mut min = Self::MAX_STR_LEN;
                 let mut idx = 0;
-                let mut buf_idx = 0;
                 while idx < Self::ALL_VALUES.len() {
-                    let value = Self::ALL_VALUES[idx];
-                    let mut value_idx = 0;
-                    while value_idx < value.len() {
-                        buf[buf_idx] = value.as_bytes()[value_idx];
-                        value_idx += 1;
-                        buf_idx += 1
-                    }
-
-                    if idx != Self::ALL_VALUES.len() - 1 {
-                        buf[buf_idx] = b',';
-                        buf_idx += 1;
+                    if Self::ALL_VALUES[idx].len() < min {
+                        min = Self::ALL_VALUES[idx].len();
                     }
-                    idx += 1
+                    idx += 1;
                 }
-                buf
+                min
             };
 
-            // we assemble this in a funny way due to issues with slicing in const
-            #[doc = "&'static str of `Self::ALL_VALUE_BYTES`"]
-            const ALL_VALUE_STR: &str = {
-                match str::from_utf8(&Self::ALL_VALUE_BYTES) {
-                    Ok(o) => o,
-                    Err(_) => panic!(),
+            #[doc = "The value as a NUL-terminated C string, assembled at compile time"]
+            pub const fn as_c_str(&self) -> &'static ::core::ffi::CStr {
+                match *self {
+                    $(Self::$variant => {
+                        const BYTES: [u8; $ty::$variant.as_str().len() + 1] = $crate::nul_terminated($ty::$variant.as_str());
+                        const C_STR: &::core::ffi::CStr = match ::core::ffi::CStr::from_bytes_with_nul(&BYTES) {
+                            Ok(c_str) => c_str,
+                            Err(_) => panic!(concat!("the value of ", stringify!($variant), " contains a NUL byte")),
+                        };
+                        C_STR
+                    })*
                 }
-            };
-        }
-
-        impl $ty {
-            #[doc = "len() of this variant's str equivalent"]
-            pub const fn len(&self) -> usize {
-                self.as_str().len()
             }
 
-            #[doc = "equivalent to str::is_empty"]
-            pub const fn is_empty(&self) -> bool {
-                self.as_str().is_empty()
-            }
-        }
+            #[doc = "The names of the variants as written, in declaration order"]
+            pub const VARIANT_NAMES: &[&str] = &[$(stringify!($variant),)*];
 
-        $(
-            impl $ty {
-                #[doc = "Convert this enum into its repr"]
-                fn into_repr(self) -> $repr {
-                    self as $repr
+            #[doc = "The position of this variant in declaration order, regardless of its discriminant"]
+            pub const fn index(&self) -> usize {
+                #[allow(dead_code, non_camel_case_types)]
+                enum Position {
+                    $($variant,)*
+                }
+                match *self {
+                    $(Self::$variant => Position::$variant as usize,)*
                 }
             }
 
-            impl From<$ty> for $repr {
-                fn from(v: $ty) -> $repr {
-                    v as $repr
+            #[doc = "The variant at `index` in declaration order, the inverse of `Self::index`"]
+            pub const fn from_index(index: usize) -> Option<Self> {
+                #[allow(dead_code, non_camel_case_types)]
+                enum Position {
+                    $($variant,)*
                 }
+                $(
+                    if index == Position::$variant as usize {
+                        return Some(Self::$variant);
+                    }
+                )*
+                None
             }
-        )?
 
-        impl std::fmt::Display for $ty {
-            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                <str as std::fmt::Display>::fmt(self.as_str(), fmt)
+            #[doc = "The variant declared after this one, wrapping around to the first"]
+            pub const fn next(self) -> Self {
+                match self.checked_next() {
+                    Some(next) => next,
+                    None => match Self::from_index(0) {
+                        Some(first) => first,
+                        None => unreachable!(),
+                    },
+                }
             }
-        }
 
-        impl std::borrow::Borrow<str> for $ty {
-            fn borrow(&self) -> &str {
-                self.as_str()
+            #[doc = "The variant declared before this one, wrapping around to the last"]
+            pub const fn prev(self) -> Self {
+                match self.checked_prev() {
+                    Some(prev) => prev,
+                    None => match Self::from_index(Self::NUM_VARIANTS.wrapping_sub(1)) {
+                        Some(last) => last,
+                        None => unreachable!(),
+                    },
+                }
             }
-        }
 
-        impl std::hash::Hash for $ty {
-            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-                <str as std::hash::Hash>::hash(self.as_str(), state)
+            #[doc = "The variant declared after this one, or `None` for the last"]
+            pub const fn checked_next(self) -> Option<Self> {
+                Self::from_index(self.index() + 1)
             }
-        }
-
-        impl<'a> std::ops::Add<$ty> for std::borrow::Cow<'a, str> {
-            type Output = std::borrow::Cow<'a, str>;
 
-            fn add(self, rhs: $ty) -> std::borrow::Cow<'a, str> {
-                self.add(rhs.as_str())
+            #[doc = "The variant declared before this one, or `None` for the first"]
+            pub const fn checked_prev(self) -> Option<Self> {
+                match self.index().checked_sub(1) {
+                    Some(index) => Self::from_index(index),
+                    None => None,
+                }
             }
-        }
 
-        impl std::ops::Add<$ty> for String {
-            type Output = String;
-
-            fn add(self, rhs: $ty) -> String {
-                self.add(rhs.as_str())
+            #[doc = "Every variant, in declaration order"]
+            pub fn iter() -> $crate::Variants<Self> {
+                const CONSTRUCTORS: &[fn() -> $ty] = &[$(|| $ty::$variant,)*];
+                $crate::Variants::new(CONSTRUCTORS)
             }
-        }
 
-        impl<'a> std::ops::AddAssign<$ty> for std::borrow::Cow<'a, str> {
-            fn add_assign(&mut self, rhs: $ty) {
-                self.add_assign(rhs.as_str())
+            #[doc = "Every value, in declaration order. Like `Self::ALL_VALUES` as an iterator"]
+            pub fn values() -> ::core::iter::Copied<::core::slice::Iter<'static, &'static str>> {
+                Self::ALL_VALUES.iter().copied()
             }
-        }
 
-        impl std::ops::AddAssign<$ty> for String {
-            fn add_assign(&mut self, rhs: $ty) {
-                self.add_assign(rhs.as_str())
+            #[doc = "The names of the variants as written, in declaration order. Like `Self::VARIANT_NAMES` as an iterator"]
+            pub fn variant_names() -> ::core::iter::Copied<::core::slice::Iter<'static, &'static str>> {
+                Self::VARIANT_NAMES.iter().copied()
             }
-        }
 
-        $crate::str_enum_base!(AsRef $ty, [str, std::ffi::OsStr, std::path::Path, [u8]]);
+            #[doc = "Each value paired with its variant, in declaration order"]
+            pub const ALL_PAIRS: &[(&'static str, Self)] = &[$((Self::$variant.as_str(), Self::$variant),)*];
 
-        impl Extend<$ty> for String {
-            fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item = $ty> {
-                iter.into_iter().for_each(move |s| self.push_str(s.as_str()))
+            #[doc = "The other valid forms of this variant, in every scope, as written in brackets after its value"]
+            pub const fn aliases(&self) -> &'static [&'static str] {
+                match *self {
+                    $(Self::$variant => &[$($($other_valid),*)?],)*
+                }
             }
-        }
 
-        $crate::str_enum_base!(From $ty, [std::sync::Arc<str>, Box<str>, std::rc::Rc<str>, String, Vec<u8>]);
-        $crate::str_enum_base!(From 'a $ty, [Box<dyn std::error::Error + 'a>, Box<dyn std::error::Error + Send + Sync + 'a>, std::borrow::Cow<'a, str>]);
-        $crate::str_enum_base!(FromIterator $ty, [Box<str>, String]);
-        $crate::str_enum_base!(FromIterator 'a $ty, [std::borrow::Cow<'a, str>]);
+            #[doc = "Other valid forms of all variants, in the same order as `Self::ALL_VALUES`"]
+            pub const ALL_ALIASES: &[&[&str]] = &[$(Self::$variant.aliases(),)*];
 
-        impl<I: std::slice::SliceIndex<str>> std::ops::Index<I> for $ty {
-            type Output = <I as std::slice::SliceIndex<str>>::Output;
-
-            fn index(&self, index: I) -> &<I as std::slice::SliceIndex<str>>::Output {
-                self.as_str().index(index)
+            #[doc = "The description written after this variant as `? \"...\"`, or an empty string if it has none"]
+            pub const fn description(&self) -> &'static str {
+                match *self {
+                    $(Self::$variant => $crate::str_enum_base!(@description $($variant_description)?),)*
+                }
             }
-        }
 
-        $crate::str_enum_base!(PartialEq $ty, [std::ffi::OsStr, std::ffi::OsString, String, std::path::Path, std::path::PathBuf]);
-        $crate::str_enum_base!(PartialEq 'a $ty, [std::borrow::Cow<'a, str>]);
+            #[doc = "Descriptions of all variants, in the same order as `Self::ALL_VALUES`"]
+            pub const ALL_DESCRIPTIONS: &[&str] = &[$(Self::$variant.description(),)*];
 
-        impl PartialEq<&str> for $ty {
-            fn eq(&self, rhs: &&str) -> bool {
-                self.as_str().eq(*rhs)
+            #[doc = "The value of the variant `s` parses as with `Self::try_from_str`, turning any accepted form into the canonical one"]
+            pub fn canonicalize(s: &str) -> Option<&'static str> {
+                Self::try_from_str(s).map(|variant| variant.as_str())
             }
-        }
 
-        impl PartialEq<$ty> for &str {
-            fn eq(&self, rhs: &$ty) -> bool {
-                self.eq(&rhs.as_str())
+            #[doc = "Whether `s` is this variant's value ignoring ASCII case, whatever `#[parse]` options are set. Alternate forms don't count"]
+            pub fn eq_str_ignore_ascii_case(&self, s: &str) -> bool {
+                self.as_str().eq_ignore_ascii_case(s)
             }
-        }
 
-        impl PartialEq<str> for $ty {
-            fn eq(&self, rhs: &str) -> bool {
-                self.as_str().eq(rhs)
+            #[doc = "Like `Self::try_from_str`, but always comparing values and alternate forms ignoring ASCII case, as if `#[parse(case_insensitive)]` was set"]
+            pub fn try_from_str_ignore_ascii_case(s: &str) -> Option<Self> {
+                let scope = $crate::AliasScope::Parse;
+                Self::match_detailed(s)
+                    .or_else(|| {
+                        let eq = |value: &str| s.eq_ignore_ascii_case(value);
+                        $crate::str_enum_match_by!(eq, scope, { $($variant $(($($($alias_marker)* $other_valid),*))?,)* })
+                    })
+                    .map(|(variant, _)| variant)
+                    .filter(|variant| !variant.parse_skipped())
+                    .or_else(Self::fallback)
             }
-        }
 
-        impl PartialEq<$ty> for str {
-            fn eq(&self, rhs: &$ty) -> bool {
-                self.eq(rhs.as_str())
+            #[doc = "Like `Self::try_from_str`, but ignoring whitespace around `s`, as if `#[parse(trim)]` was set"]
+            pub fn try_from_str_trimmed(s: &str) -> Option<Self> {
+                Self::try_from_str(s.trim())
             }
-        }
-
-        $crate::str_enum_base!(PartialOrd $ty, [std::ffi::OsStr, std::ffi::OsString]);
 
-        impl PartialOrd<$ty> for str {
-            fn partial_cmp(&self, rhs: &$ty) -> Option<std::cmp::Ordering> {
-                self.partial_cmp(rhs.as_str())
+            #[doc = "Like `Self::try_from_str`, for bytes. Input that isn't valid UTF-8 gives `None`"]
+            pub fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
+                ::core::str::from_utf8(bytes).ok().and_then(Self::try_from_str)
             }
-        }
 
-        impl PartialOrd<str> for $ty {
-            fn partial_cmp(&self, rhs: &str) -> Option<std::cmp::Ordering> {
-                self.as_str().partial_cmp(rhs)
+            #[doc = "Like `Self::try_from_str`, but usable in const contexts. `#[parse(case_insensitive)]` applies, `#[parse(case_fold)]` does not, `#[parse(trim)]` only trims ASCII whitespace, and deprecated forms aren't logged"]
+            pub const fn try_from_str_const(s: &str) -> Option<Self> {
+                let found: Option<Self> = $crate::str_enum_base!(@parse_opts_const s, [$($($parse_opt)*)?], { $($variant $(($($($alias_marker)* $other_valid),*))?,)* });
+                match found {
+                    Some(variant) if !variant.parse_skipped() => Some(variant),
+                    _ => Self::fallback(),
+                }
             }
-        }
 
-        impl PartialOrd<$ty> for &str {
-            fn partial_cmp(&self, rhs: &$ty) -> Option<std::cmp::Ordering> {
-                self.partial_cmp(&rhs.as_str())
+            #[doc = "The value or alternate form accepted by `Self::try_from_str` that is closest to `s` by edit distance, if one is close enough to be a likely typo"]
+            pub fn suggest(s: &str) -> Option<&'static str> {
+                let spellings: &[(bool, &'static str, $crate::AliasScope)] = &[
+                    $(
+                        (Self::$variant.parse_skipped(), Self::$variant.as_str(), $crate::AliasScope::All),
+                        $($((Self::$variant.parse_skipped(), $other_valid, $crate::str_enum_alias!(@scope $($alias_marker)*)),)*)?
+                    )*
+                ];
+                $crate::closest_spelling(
+                    s,
+                    spellings
+                        .iter()
+                        .filter(|(skipped, _, scope)| !skipped && scope.allows($crate::AliasScope::Parse))
+                        .map(|(_, spelling, _)| *spelling),
+                )
             }
-        }
 
-        impl PartialOrd<&str> for $ty {
-            fn partial_cmp(&self, rhs: &&str) -> Option<std::cmp::Ordering> {
-                self.as_str().partial_cmp(*rhs)
+            #[doc = "The variant whose value or alternate form accepted by `Self::try_from_str` is the longest prefix of `input`, and the rest of `input` after it"]
+            pub fn match_prefix(input: &str) -> Option<(Self, &str)> {
+                let spellings: &[(fn() -> Self, &'static str, $crate::AliasScope)] = &[
+                    $(
+                        (|| Self::$variant, Self::$variant.as_str(), $crate::AliasScope::All),
+                        $($((|| Self::$variant, $other_valid, $crate::str_enum_alias!(@scope $($alias_marker)*)),)*)?
+                    )*
+                ];
+                spellings
+                    .iter()
+                    .map(|(variant, spelling, scope)| (variant(), *spelling, *scope))
+                    .filter(|(variant, spelling, scope)| {
+                        !variant.parse_skipped() && scope.allows($crate::AliasScope::Parse) && input.starts_with(spelling)
+                    })
+                    .fold(None::<(Self, &'static str)>, |longest, (variant, spelling, _)| match longest {
+                        Some((_, longest_spelling)) if longest_spelling.len() >= spelling.len() => longest,
+                        _ => Some((variant, spelling)),
+                    })
+                    .map(|(variant, spelling)| (variant, &input[spelling.len()..]))
             }
-        }
-
-        impl std::net::ToSocketAddrs for $ty {
-            type Iter = std::vec::IntoIter<std::net::SocketAddr>;
 
-            fn to_socket_addrs(&self) -> std::io::Result<std::vec::IntoIter<std::net::SocketAddr>> {
-                <str as std::net::ToSocketAddrs>::to_socket_addrs(self.as_str())
+            #[doc = "Every occurrence of a value in `haystack` as its byte offset and variant, by offset and then declaration order. Occurrences may overlap, alternate forms and `#[parse(skip)]` variants aren't searched for"]
+            pub fn find_all(haystack: &str) -> impl Iterator<Item = (usize, Self)> {
+                let variants: [fn() -> Self; Self::NUM_VARIANTS] = [$(|| Self::$variant,)*];
+                haystack.char_indices().flat_map(move |(offset, _)| {
+                    let rest = &haystack[offset..];
+                    Self::ALL_VALUES
+                        .iter()
+                        .zip(variants)
+                        .filter(move |(value, variant)| !value.is_empty() && rest.starts_with(**value) && !variant().parse_skipped())
+                        .map(move |(_, variant)| (offset, variant()))
+                })
             }
-        }
 
-    };
-    (AsRef $self:ident, [$($other:ty),*]) => {
-        $(
-            impl AsRef<$other> for $self {
-                fn as_ref(&self) -> &$other {
-                    <str as AsRef<$other>>::as_ref(self.as_str())
+            #[doc = "Split `s` on `sep` and parse every element with `Self::try_from_str` after trimming surrounding whitespace. Blank input is an empty list.\nThe error holds the 0-based index and value of the first element that didn't parse."]
+            pub fn parse_list(s: &str, sep: char) -> Result<$crate::alloc::vec::Vec<Self>, $crate::ParseListError> {
+                if s.trim().is_empty() {
+                    return Ok($crate::alloc::vec::Vec::new());
                 }
+                s.split(sep)
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let value = value.trim();
+                        Self::try_from_str(value).ok_or_else(|| $crate::ParseListError { index, value: $crate::alloc::string::String::from(value) })
+                    })
+                    .collect()
             }
-        )*
-    };
-    (From $self:ident, [$($other:ty),*]) => {
-        $(
-            impl From<$self> for $other {
-                fn from(val: $self) -> $other {
-                    From::from(val.as_str())
+
+            #[doc = "Whether `self` is the same variant as `other`, usable in const contexts and without `PartialEq`"]
+            pub const fn is(&self, other: &Self) -> bool {
+                match (self, other) {
+                    $((Self::$variant, Self::$variant) => true,)*
+                    _ => false,
                 }
             }
-        )*
-    };
-    (From 'a $self:ident, [$($other:ty),*]) => {
-        $(
-            impl<'a> From<$self> for $other {
-                fn from(val: $self) -> $other {
-                    From::from(val.as_str())
+
+            $($(
+                #[doc = concat!("Whether this is `Self::", stringify!($variant), "`, named by its `#[predicate(...)]`")]
+                pub const fn $variant_predicate(&self) -> bool {
+                    matches!(self, Self::$variant)
                 }
-            }
-        )*
-    };
-    (FromIterator $self:ident, [$($other:ty),*]) => {
-        $(
-            impl std::iter::FromIterator<$self> for $other {
-                fn from_iter<T>(iter: T) -> $other
-                where
-                    T: IntoIterator<Item = $self>
-                {
-                    <$other as std::iter::FromIterator<&'static str>>::from_iter(iter.into_iter().map(|s| s.as_str()))
+            )?)*
+
+            #[doc = "Whether this variant is marked `#[parse(skip)]`, meaning it is never produced by parsing or deserializing"]
+            pub const fn parse_skipped(&self) -> bool {
+                match *self {
+                    $(Self::$variant => $crate::str_enum_base!(@parse_skip $($($variant_parse_opt)*)?),)*
                 }
             }
-        )*
-    };
-    (FromIterator 'a $self:ident, [$($other:ty),*]) => {
-        $(
-            impl<'a> std::iter::FromIterator<$self> for $other {
-                fn from_iter<T>(iter: T) -> $other
-                where
-                    T: IntoIterator<Item = $self>
-                {
-                    <$other as std::iter::FromIterator<&'static str>>::from_iter(iter.into_iter().map(|s| s.as_str()))
+
+            #[doc = "Whether this variant is marked `#[deprecated]`. Deprecated variants are still parsed and deserialized"]
+            pub const fn is_deprecated(&self) -> bool {
+                match *self {
+                    $(Self::$variant => $crate::str_enum_base!(@deprecated $(deprecated $($variant_deprecated)*)?),)*
                 }
             }
-        )*
-    };
-    (PartialEq $self:ident, [$($other:ty),*]) => {
-        $(
-            impl PartialEq<$self> for $other {
-                fn eq(&self, rhs: &$self) -> bool {
-                    self.eq(rhs.as_str())
-                }
+
+            #[doc = "The variant marked `#[parse(fallback)]`, which `Self::try_from_str` and deserializing return for input that matches nothing else"]
+            pub const fn fallback() -> Option<Self> {
+                $(
+                    if $crate::str_enum_base!(@parse_fallback $($($variant_parse_opt)*)?) {
+                        return Some(Self::$variant);
+                    }
+                )*
+                None
             }
 
-            impl PartialEq<$other> for $self {
-                fn eq(&self, rhs: &$other) -> bool {
-                    self.as_str().eq(rhs)
+            #[doc = "Number of variants not marked `#[parse(skip)]`. You do not need this."]
+            const NUM_EXPECTED_VALUES: usize = {
+                let mut len = 0usize;
+                let mut idx = 0usize;
+                while idx < Self::ALL_VARIANTS.len() {
+                    if !Self::ALL_VARIANTS[idx].parse_skipped() {
+                        len += 1;
+                    }
+                    idx += 1
                 }
-            }
-        )*
-    };
-    (PartialEq 'a $self:ident, [$($other:ty),*]) => {
-        $(
-            impl<'a> PartialEq<$self> for $other {
-                fn eq(&self, rhs: &$self) -> bool {
-                    self.eq(rhs.as_str())
+                len
+            };
+
+            #[doc = "Values of the variants not marked `#[parse(skip)]`, these are what error messages list as expected. You do not need this."]
+            const EXPECTED_VALUES: [&str; Self::NUM_EXPECTED_VALUES] = {
+                let mut buf = [""; Self::NUM_EXPECTED_VALUES];
+                let mut idx = 0usize;
+                let mut buf_idx = 0usize;
+                while idx < Self::ALL_VARIANTS.len() {
+                    if !Self::ALL_VARIANTS[idx].parse_skipped() {
+                        buf[buf_idx] = Self::ALL_VALUES[idx];
+                        buf_idx += 1;
+                    }
+                    idx += 1
                 }
-            }
+                buf
+            };
 
-            impl<'a> PartialEq<$other> for $self {
-                fn eq(&self, rhs: &$other) -> bool {
-                    self.as_str().eq(rhs)
+            #[doc = "Total length of `Self::EXPECTED_VALUES + 1 byte separator. You do not need this."]
+            const ALL_VALUES_STR_LEN: usize = {
+                let mut len = 0usize;
+                let mut idx = 0usize;
+                while idx < Self::EXPECTED_VALUES.len() {
+                    let value = Self::EXPECTED_VALUES[idx];
+                    len += value.len() + 1;
+                    idx += 1
                 }
-            }
-        )*
-    };
-    (PartialOrd $self:ident, [$($other:ty),*]) => {
-        $(
-            impl PartialOrd<$self> for $other {
-                fn partial_cmp(&self, rhs: &$self) -> Option<std::cmp::Ordering> {
-                    self.partial_cmp(rhs.as_str())
+                len.saturating_sub(1)
+            };
+
+            #[doc = "Fixed size byte array of `Self::EXPECTED_VALUES` joined by a comma separator. You do not need this."]
+            const ALL_VALUE_BYTES: [u8; Self::ALL_VALUES_STR_LEN] = {
+                let mut buf = [0u8; Self::ALL_VALUES_STR_LEN];
+                let mut idx = 0;
+                let mut buf_idx = 0;
+                while idx < Self::EXPECTED_VALUES.len() {
+                    let value = Self::EXPECTED_VALUES[idx];
+                    let mut value_idx = 0;
+                    while value_idx < value.len() {
+                        buf[buf_idx] = value.as_bytes()[value_idx];
+                        value_idx += 1;
+                        buf_idx += 1
+                    }
+
+                    if idx != Self::EXPECTED_VALUES.len() - 1 {
+                        buf[buf_idx] = b',';
+                        buf_idx += 1;
+                    }
+                    idx += 1
                 }
-            }
-        )*
-    };
-    (PartialOrd 'a $self:ident, [$($other:ty),*]) => {
-        $(
-            impl<'a> PartialOrd<$self> for $other {
-                fn partial_cmp(&self, rhs: &$self) -> Option<std::cmp::Ordering> {
-                    self.partial_cmp(rhs.as_str())
+                buf
+            };
+
+            // we assemble this in a funny way due to issues with slicing in const
+            #[doc = "&'static str of `Self::ALL_VALUE_BYTES`"]
+            const ALL_VALUE_STR: &str = {
+                match str::from_utf8(&Self::ALL_VALUE_BYTES) {
+                    Ok(o) => o,
+                    Err(_) => panic!(),
                 }
-            }
-        )*
-    };
-    (FromStr $(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
-        $(
-            #[derive(Debug, Clone, Copy, Default)]
-            $vis struct $error_ty;
+            };
 
-            impl $error_ty {
-                #[doc = "Length of Self's error string. You do not need this."]
-                const EXPECTED_STR_LEN: usize = "expected one of [".len() + "]".len() + $ty::ALL_VALUES_STR_LEN;
-                #[doc = "Bytes of Self's error string. You do not need this."]
-                const EXPECTED_STR_BYTES: [u8; Self::EXPECTED_STR_LEN] = {
-                    let mut buf = [0u8; Self::EXPECTED_STR_LEN];
-                    let mut idx = 0;
+            #[doc = "`Self::EXPECTED_VALUES` quoted and separated like `\"Value0\", \"Value1\" or \"Value2\"`, which error messages list as expected"]
+            pub const EXPECTED_LIST: &str = {
+                match str::from_utf8(&Self::EXPECTED_LIST_BYTES) {
+                    Ok(o) => o,
+                    Err(_) => panic!(),
+                }
+            };
 
-                    let first_part = b"expected one of [";
+            #[doc = "Length of `Self::EXPECTED_LIST`. You do not need this."]
+            const EXPECTED_LIST_LEN: usize = Self::write_expected_list(&mut []);
 
-                    while idx < first_part.len() {
-                        buf[idx] = first_part[idx];
-                        idx += 1
-                    }
+            #[doc = "Bytes of `Self::EXPECTED_LIST`. You do not need this."]
+            const EXPECTED_LIST_BYTES: [u8; Self::EXPECTED_LIST_LEN] = {
+                let mut buf = [0u8; Self::EXPECTED_LIST_LEN];
+                Self::write_expected_list(&mut buf);
+                buf
+            };
 
-                    while idx < first_part.len() + $ty::ALL_VALUES_STR_LEN {
-                        buf[idx] = $ty::ALL_VALUE_BYTES[idx - first_part.len()];
-                        idx +=1
+            #[doc = "Writes `Self::EXPECTED_LIST` into `buf` if it is non-empty, returning the length either way. You do not need this."]
+            const fn write_expected_list(buf: &mut [u8]) -> usize {
+                let mut idx = 0;
+                let mut value_idx = 0;
+                while value_idx < Self::EXPECTED_VALUES.len() {
+                    if value_idx != 0 {
+                        idx = Self::push_bytes(buf, idx, if value_idx == Self::EXPECTED_VALUES.len() - 1 { b" or " } else { b", " });
                     }
-                    buf[Self::EXPECTED_STR_LEN - 1] = b']';
+                    idx = Self::push_bytes(buf, idx, b"\"");
+                    idx = Self::push_bytes(buf, idx, Self::EXPECTED_VALUES[value_idx].as_bytes());
+                    idx = Self::push_bytes(buf, idx, b"\"");
+                    value_idx += 1
+                }
+                idx
+            }
+        }
 
-                    buf
-                };
-                #[doc = "&'static str of `Self::EXPECTED_STR_BYTES`. You do not need this."]
-                const EXPECTED_STR: &str = {
-                    match str::from_utf8(&Self::EXPECTED_STR_BYTES) {
-                        Ok(o) => o,
-                        Err(_) => panic!(),
-                    }
-                };
+        impl $ty {
+            #[doc = "len() of this variant's str equivalent"]
+            pub const fn len(&self) -> usize {
+                self.as_str().len()
             }
 
-            impl std::fmt::Display for $error_ty {
-                fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    <str as std::fmt::Display>::fmt(Self::EXPECTED_STR, fmt)
-                }
+            #[doc = "equivalent to str::is_empty"]
+            pub const fn is_empty(&self) -> bool {
+                self.as_str().is_empty()
             }
+        }
 
-            impl std::error::Error for $error_ty {}
+        #[allow(deprecated)]
+        impl $ty {
+            #[doc = "GraphQL SDL declaring an equivalent enum, using the variant names as the enum value names (values such as `image/png` are rarely valid GraphQL names) with each value in a trailing comment"]
+            pub const GRAPHQL_SDL: &str = {
+                match str::from_utf8(&Self::GRAPHQL_SDL_BYTES) {
+                    Ok(o) => o,
+                    Err(_) => panic!(),
+                }
+            };
 
-            impl std::str::FromStr for $ty {
-                type Err = $error_ty;
+            #[doc = "Length of `Self::GRAPHQL_SDL`. You do not need this."]
+            const GRAPHQL_SDL_LEN: usize = Self::write_graphql_sdl(&mut []);
 
-                fn from_str(s: &str) -> Result<$ty, Self::Err> {
-                    match Self::try_from_str(s) {
-                        Some(variant) => Ok(variant),
-                        None => Err($error_ty)
-                    }
+            #[doc = "Bytes of `Self::GRAPHQL_SDL`. You do not need this."]
+            const GRAPHQL_SDL_BYTES: [u8; Self::GRAPHQL_SDL_LEN] = {
+                let mut buf = [0u8; Self::GRAPHQL_SDL_LEN];
+                Self::write_graphql_sdl(&mut buf);
+                buf
+            };
+
+            #[doc = "Writes `Self::GRAPHQL_SDL` into `buf` if it is non-empty, returning the length either way. You do not need this."]
+            const fn write_graphql_sdl(buf: &mut [u8]) -> usize {
+                let mut idx = Self::push_bytes(buf, 0, b"enum ");
+                idx = Self::push_bytes(buf, idx, stringify!($ty).as_bytes());
+                idx = Self::push_bytes(buf, idx, b" {\n");
+
+                let mut variant_idx = 0;
+                while variant_idx < Self::ALL_VALUES.len() {
+                    idx = Self::push_bytes(buf, idx, b"  ");
+                    idx = Self::push_bytes(buf, idx, Self::IDL_NAMES[variant_idx].as_bytes());
+                    idx = Self::push_bytes(buf, idx, b" # \"");
+                    idx = Self::push_bytes(buf, idx, Self::ALL_VALUES[variant_idx].as_bytes());
+                    idx = Self::push_bytes(buf, idx, b"\"\n");
+                    variant_idx += 1
                 }
+
+                Self::push_bytes(buf, idx, b"}\n")
             }
 
-            impl TryFrom<&str> for $ty {
-                type Error = $error_ty;
+            #[doc = "Variant names without the `r#` of raw identifiers, for `Self::GRAPHQL_SDL` and `Self::PROTOBUF_ENUM`. You do not need this."]
+            const IDL_NAMES: &[&str] = &[$($crate::unraw(stringify!($variant)),)*];
 
-                fn try_from(s: &str) -> Result<$ty, Self::Error> {
-                    match Self::try_from_str(s) {
-                        Some(variant) => Ok(variant),
-                        None => Err($error_ty)
+            #[doc = "Copies `bytes` into `buf` at `idx` if `buf` is non-empty, returning the index after them. You do not need this."]
+            const fn push_bytes(buf: &mut [u8], mut idx: usize, bytes: &[u8]) -> usize {
+                let mut byte_idx = 0;
+                while byte_idx < bytes.len() {
+                    if !buf.is_empty() {
+                        buf[idx] = bytes[byte_idx];
                     }
+                    idx += 1;
+                    byte_idx += 1
                 }
+                idx
             }
 
-            impl TryFrom<String> for $ty {
-                type Error = $error_ty;
+            #[doc = "Protobuf declaration of an equivalent enum, using the variant names and discriminants with each value in a trailing comment.\n`None` unless every discriminant fits in an `int32` and the first one is 0, as proto3 requires"]
+            pub const PROTOBUF_ENUM: Option<&str> = {
+                let mut variant_idx = 0;
+                while variant_idx < Self::PROTOBUF_DISCRIMINANTS.len() {
+                    let discriminant = Self::PROTOBUF_DISCRIMINANTS[variant_idx];
+                    if discriminant < i32::MIN as i128 || discriminant > i32::MAX as i128 {
+                        break;
+                    }
+                    variant_idx += 1
+                }
+                match (Self::PROTOBUF_DISCRIMINANTS.first(), str::from_utf8(&Self::PROTOBUF_ENUM_BYTES)) {
+                    (Some(0), Ok(o)) if variant_idx == Self::PROTOBUF_DISCRIMINANTS.len() => Some(o),
+                    (_, Ok(_)) => None,
+                    (_, Err(_)) => panic!(),
+                }
+            };
 
-                fn try_from(s: String) -> Result<$ty, Self::Error> {
-                    match Self::try_from_str(&s) {
-                        Some(variant) => Ok(variant),
-                        None => Err($error_ty)
+            #[doc = "Discriminants of all variants, for `Self::PROTOBUF_ENUM`. You do not need this."]
+            const PROTOBUF_DISCRIMINANTS: &[i128] = &[$($ty::$variant as i128,)*];
+
+            #[doc = "Length of `Self::PROTOBUF_ENUM`. You do not need this."]
+            const PROTOBUF_ENUM_LEN: usize = Self::write_protobuf_enum(&mut []);
+
+            #[doc = "Bytes of `Self::PROTOBUF_ENUM`. You do not need this."]
+            const PROTOBUF_ENUM_BYTES: [u8; Self::PROTOBUF_ENUM_LEN] = {
+                let mut buf = [0u8; Self::PROTOBUF_ENUM_LEN];
+                Self::write_protobuf_enum(&mut buf);
+                buf
+            };
+
+            #[doc = "Writes `Self::PROTOBUF_ENUM` into `buf` if it is non-empty, returning the length either way. You do not need this."]
+            const fn write_protobuf_enum(buf: &mut [u8]) -> usize {
+                let mut idx = Self::push_bytes(buf, 0, b"enum ");
+                idx = Self::push_bytes(buf, idx, stringify!($ty).as_bytes());
+                idx = Self::push_bytes(buf, idx, b" {\n");
+
+                let mut variant_idx = 0;
+                while variant_idx < Self::IDL_NAMES.len() {
+                    idx = Self::push_bytes(buf, idx, b"  ");
+                    idx = Self::push_bytes(buf, idx, Self::IDL_NAMES[variant_idx].as_bytes());
+                    idx = Self::push_bytes(buf, idx, b" = ");
+
+                    let discriminant = Self::PROTOBUF_DISCRIMINANTS[variant_idx];
+                    if discriminant < 0 {
+                        idx = Self::push_bytes(buf, idx, b"-");
+                    }
+                    let mut magnitude = discriminant.unsigned_abs();
+                    let mut digits = [0u8; 39];
+                    let mut digit_count = 0;
+                    loop {
+                        digits[digits.len() - 1 - digit_count] = b'0' + (magnitude % 10) as u8;
+                        digit_count += 1;
+                        magnitude /= 10;
+                        if magnitude == 0 {
+                            break;
+                        }
                     }
+                    let (_, digits) = digits.split_at(digits.len() - digit_count);
+                    idx = Self::push_bytes(buf, idx, digits);
+
+                    idx = Self::push_bytes(buf, idx, b"; // \"");
+                    idx = Self::push_bytes(buf, idx, Self::ALL_VALUES[variant_idx].as_bytes());
+                    idx = Self::push_bytes(buf, idx, b"\"\n");
+                    variant_idx += 1
                 }
+
+                Self::push_bytes(buf, idx, b"}\n")
             }
 
-            impl<'a> TryFrom<&'a std::ffi::OsStr> for $ty {
-                type Error = $crate::Utf8EnumError<$error_ty>;
+            #[doc = "Regex alternation of all values, longest first so a regex engine tries them before their prefixes, with regex syntax escaped. Wrap it in a group like `(?:...)` to embed it in a larger pattern"]
+            pub const REGEX_PATTERN: &str = {
+                match str::from_utf8(&Self::REGEX_PATTERN_BYTES) {
+                    Ok(o) => o,
+                    Err(_) => panic!(),
+                }
+            };
 
-                fn try_from(value: &'a std::ffi::OsStr) -> Result<$ty, Self::Error> {
-                    <&'a str as TryFrom<&'a std::ffi::OsStr>>::try_from(value)
-                    .map_err($crate::Utf8EnumError::Utf8)
-                    .and_then(|s| $ty::try_from(s).map_err($crate::Utf8EnumError::InvalidVariant))
+            #[doc = "Length of `Self::REGEX_PATTERN`. You do not need this."]
+            const REGEX_PATTERN_LEN: usize = Self::write_regex_pattern(&mut []);
+
+            #[doc = "Bytes of `Self::REGEX_PATTERN`. You do not need this."]
+            const REGEX_PATTERN_BYTES: [u8; Self::REGEX_PATTERN_LEN] = {
+                let mut buf = [0u8; Self::REGEX_PATTERN_LEN];
+                Self::write_regex_pattern(&mut buf);
+                buf
+            };
+
+            #[doc = "Writes `Self::REGEX_PATTERN` into `buf` if it is non-empty, returning the length either way. You do not need this."]
+            const fn write_regex_pattern(buf: &mut [u8]) -> usize {
+                let mut written = [false; Self::NUM_VARIANTS];
+                let mut idx = 0;
+
+                let mut count = 0;
+                while count < Self::ALL_VALUES.len() {
+                    // the longest value not written yet, the first one declared on ties
+                    let mut longest = 0;
+                    while written[longest] {
+                        longest += 1
+                    }
+                    let mut variant_idx = longest + 1;
+                    while variant_idx < Self::ALL_VALUES.len() {
+                        if !written[variant_idx] && Self::ALL_VALUES[variant_idx].len() > Self::ALL_VALUES[longest].len() {
+                            longest = variant_idx;
+                        }
+                        variant_idx += 1
+                    }
+                    written[longest] = true;
+
+                    if count != 0 {
+                        idx = Self::push_bytes(buf, idx, b"|");
+                    }
+                    let value = Self::ALL_VALUES[longest].as_bytes();
+                    let mut byte_idx = 0;
+                    while byte_idx < value.len() {
+                        if matches!(value[byte_idx], b'\\' | b'.' | b'+' | b'*' | b'?' | b'(' | b')' | b'|' | b'[' | b']' | b'{' | b'}' | b'^' | b'$' | b'#' | b'&' | b'-' | b'~') {
+                            idx = Self::push_bytes(buf, idx, b"\\");
+                        }
+                        idx = Self::push_bytes(buf, idx, &[value[byte_idx]]);
+                        byte_idx += 1
+                    }
+                    count += 1
                 }
+
+                idx
             }
-        )?
-    }
-}
+        }
 
-#[cfg(feature = "strum")]
-#[macro_export]
-macro_rules! str_enum_strum {
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
-        impl $crate::strum::EnumCount for $ty {
-            const COUNT: usize = $ty::ALL_VARIANTS.len();
+        #[allow(deprecated)]
+        impl $ty {
+            #[doc = "Like `Self::try_from_str`, but uses `eq(s, candidate)` to compare `s` against each value and alternate valid form, returning the first variant it matches"]
+            pub fn try_from_str_by(s: &str, eq: impl Fn(&str, &str) -> bool) -> Option<Self> {
+                $(
+                    if !Self::$variant.parse_skipped() {
+                        if eq(s, Self::$variant.as_str()) {
+                            return Some(Self::$variant);
+                        }
+                        $($(
+                            if $crate::str_enum_alias!(@scope $($alias_marker)*).allows($crate::AliasScope::Parse) && eq(s, $other_valid) {
+                                $crate::str_enum_alias!(@used [$($alias_marker)*] $other_valid);
+                                return Some(Self::$variant);
+                            }
+                        )*)?
+                    }
+                )*
+                None
+            }
         }
 
         $(
-            impl $crate::strum::IntoDiscriminant for $ty {
-                type Discriminant = $repr;
+            impl $ty {
+                #[doc = "Convert this enum into its repr"]
+                fn into_repr(self) -> $repr {
+                    self as $repr
+                }
+            }
 
-                fn discriminant(&self) -> Self::Discriminant {
-                    self.into_repr()
+            impl From<$ty> for $repr {
+                fn from(v: $ty) -> $repr {
+                    v as $repr
                 }
             }
         )?
 
-        impl $crate::strum::IntoEnumIterator for $ty {
-            type Iterator = std::array::IntoIter<$ty, {$ty::NUM_VARIANTS}>;
+        $crate::str_enum_base!(@from_repr $ty, [$($repr)?], [$($error_ty)?], { $($variant,)* });
 
-            fn iter() -> Self::Iterator {
-                [$(Self::$variant,)*].into_iter()
-            }
-        }
+        $crate::str_enum_base!(StrType $vis $ty, [$($str_type)?], { $($variant,)* });
 
-        impl $crate::strum::VariantArray for $ty {
-            const VARIANTS: &'static [Self] = Self::ALL_VARIANTS;
-        }
+        $crate::str_enum_base!(CatchAll $vis $ty, [$($catch_all)?], { $($variant,)* });
 
-        impl $crate::strum::VariantIterator for $ty {
-            type Iterator = std::array::IntoIter<$ty, {$ty::NUM_VARIANTS}>;
+        $crate::str_enum_base!(EnumMap $vis $ty, [$($enum_map)?], { $($variant,)* });
 
-            fn iter() -> Self::Iterator {
-                [$(Self::$variant,)*].into_iter()
-            }
-        }
+        $crate::str_enum_base!(EnumSet $vis $ty, [$($enum_set)?], { $($variant,)* });
 
-        impl $crate::strum::VariantNames for $ty {
-            const VARIANTS: &'static [&'static str] = &[$(stringify!($variant),)*];
-        }
+        $crate::str_enum_base!(@constant $ty [$($const_fn: $const_ty $(, $const_from_fn)?)?] { $($variant $(: $variant_const)?,)* });
 
-        impl $crate::strum::VariantMetadata for $ty {
-            const VARIANT_COUNT: usize = Self::ALL_VARIANTS.len();
-            const VARIANT_NAMES: &'static [&'static str] = &[$(stringify!($variant),)*];
+        const _: () = assert!(
+            0 $(+ $crate::str_enum_base!(@parse_fallback $($($variant_parse_opt)*)?) as usize)* <= 1,
+            "only one variant can be marked `#[parse(fallback)]`"
+        );
 
-            fn variant_name(&self) -> &'static str {
-                match self {
-                    $(Self::$variant => stringify!($variant),)*
-                }
-            }
-        }
-    };
-}
+        $crate::str_enum_base!(@default $ty [$($($variant_default $variant)?)*]);
 
-#[macro_export]
-#[cfg(not(feature = "strum"))]
-macro_rules! str_enum_strum {
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {};
-}
+        #[allow(deprecated)]
+        const _: () = assert!(
+            !$crate::spellings_collide(&[
+                $(
+                    ($ty::$variant.as_str(), $ty::$variant.as_str(), $crate::AliasScope::All, true),
+                    $($(($other_valid, $ty::$variant.as_str(), $crate::str_enum_alias!(@scope $($alias_marker)*), false),)*)?
+                )*
+            ]),
+            concat!("two variants of `", stringify!($ty), "` share a value, or a valid form is another variant's value or valid form in the same scope")
+        );
 
-#[macro_export]
-#[cfg(feature = "serde")]
-macro_rules! str_enum_serde {
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
-        impl $ty {
-            #[doc = "Length of Self's serde deserialize error. You do not need this."]
-            const SERDE_EXPECTED_STR_LEN: usize = "one of [".len() + "]".len() + Self::ALL_VALUES_STR_LEN;
-            #[doc = "Bytes of Self's serde deserialize error. You do not need this."]
-            const SERDE_EXPECTED_STR_BYTES: [u8; Self::SERDE_EXPECTED_STR_LEN] = {
-                let mut buf = [0u8; Self::SERDE_EXPECTED_STR_LEN];
-                let mut idx = 0;
+        $crate::str_enum_std!($ty, [$($error_ty)?], [$($($skip_impl)*)?]);
 
-                let first_part = b"one of [";
+        $crate::str_enum_base!(@include_macro ($) $ty [$($variant)*] { $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)* } { $([$($($variant_parse_opt)*)?] [$variant $($val)? ($($([$($alias_marker)*] $other_valid)*)?)])* });
 
-                while idx < first_part.len() {
-                    buf[idx] = first_part[idx];
-                    idx += 1
+        $($crate::str_enum_base!(@variant_value $ty $variant $($val)?);)*
+
+        $crate::str_enum_base!(@check_skip_impls [$($($skip_impl)*)?]);
+
+        $($crate::str_enum_base!(@cmp $ty $cmp);)?
+
+        $crate::str_enum_base!(@unless_skipped Display [$($($skip_impl)*)?] {
+            impl ::core::fmt::Display for $ty {
+                fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    <str as ::core::fmt::Display>::fmt(self.display_str(), fmt)
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped Borrow [$($($skip_impl)*)?] {
+            impl ::core::borrow::Borrow<str> for $ty {
+                fn borrow(&self) -> &str {
+                    self.as_str()
                 }
+            }
+        });
 
-                while idx < first_part.len() + Self::ALL_VALUES_STR_LEN {
-                    buf[idx] = Self::ALL_VALUE_BYTES[idx - first_part.len()];
-                    idx +=1
+        $crate::str_enum_base!(@unless_skipped Hash [$($($skip_impl)*)?] {
+            impl ::core::hash::Hash for $ty {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    <str as ::core::hash::Hash>::hash(self.as_str(), state)
                 }
-                buf[Self::SERDE_EXPECTED_STR_LEN - 1] = b']';
+            }
+        });
 
-                buf
-            };
+        $crate::str_enum_base!(@unless_skipped Add [$($($skip_impl)*)?] {
+            impl<'a> ::core::ops::Add<$ty> for $crate::alloc::borrow::Cow<'a, str> {
+                type Output = $crate::alloc::borrow::Cow<'a, str>;
 
-            #[doc = "&'static str of `Self::SERDE_EXPECTED_STR_BYTES`. You do not need this."]
-            const SERDE_EXPECTED_STR: &str = {
-                match str::from_utf8(&Self::SERDE_EXPECTED_STR_BYTES) {
-                    Ok(o) => o,
-                    Err(_) => panic!(),
+                fn add(self, rhs: $ty) -> $crate::alloc::borrow::Cow<'a, str> {
+                    self.add(rhs.as_str())
                 }
-            };
-        }
+            }
 
+            impl ::core::ops::Add<$ty> for $crate::alloc::string::String {
+                type Output = $crate::alloc::string::String;
 
-        $(
-            impl $crate::serde::de::Expected for $error_ty {
-                fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                    <str as std::fmt::Display>::fmt($ty::SERDE_EXPECTED_STR, formatter)
+                fn add(self, rhs: $ty) -> $crate::alloc::string::String {
+                    self.add(rhs.as_str())
                 }
             }
-        )?
 
-        impl $crate::serde::Serialize for $ty {
+            impl<'a> ::core::ops::AddAssign<$ty> for $crate::alloc::borrow::Cow<'a, str> {
+                fn add_assign(&mut self, rhs: $ty) {
+                    self.add_assign(rhs.as_str())
+                }
+            }
+
+            impl ::core::ops::AddAssign<$ty> for $crate::alloc::string::String {
+                fn add_assign(&mut self, rhs: $ty) {
+                    self.add_assign(rhs.as_str())
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped AsRef [$($($skip_impl)*)?] {
+            $crate::str_enum_base!(AsRef $ty, [str, [u8]]);
+        });
+
+        $crate::str_enum_base!(@unless_skipped Extend [$($($skip_impl)*)?] {
+            impl Extend<$ty> for $crate::alloc::string::String {
+                fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item = $ty> {
+                    iter.into_iter().for_each(move |s| self.push_str(s.as_str()))
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped From [$($($skip_impl)*)?] {
+            $crate::str_enum_base!(From $ty, [$crate::alloc::sync::Arc<str>, $crate::alloc::boxed::Box<str>, $crate::alloc::rc::Rc<str>, $crate::alloc::string::String, $crate::alloc::vec::Vec<u8>]);
+            $crate::str_enum_base!(From 'a $ty, [$crate::alloc::boxed::Box<dyn ::core::error::Error + 'a>, $crate::alloc::boxed::Box<dyn ::core::error::Error + Send + Sync + 'a>, $crate::alloc::borrow::Cow<'a, str>]);
+            $crate::str_enum_base!(From &$ty, [$crate::alloc::sync::Arc<str>, $crate::alloc::boxed::Box<str>, $crate::alloc::rc::Rc<str>, $crate::alloc::string::String, $crate::alloc::vec::Vec<u8>]);
+            $crate::str_enum_base!(From 'a &$ty, [$crate::alloc::borrow::Cow<'a, str>]);
+        });
+
+        $crate::str_enum_base!(@unless_skipped IntoStaticStr [$($($skip_impl)*)?] {
+            $crate::str_enum_base!(From $ty, [&'static str]);
+            $crate::str_enum_base!(From &$ty, [&'static str]);
+        });
+
+        $crate::str_enum_schemars!({
+            impl $crate::schemars::JsonSchema for $ty {
+                fn schema_name() -> $crate::alloc::borrow::Cow<'static, str> {
+                    $crate::alloc::borrow::Cow::Borrowed(stringify!($ty))
+                }
+
+                fn schema_id() -> $crate::alloc::borrow::Cow<'static, str> {
+                    $crate::alloc::borrow::Cow::Borrowed(concat!(module_path!(), "::", stringify!($ty)))
+                }
+
+                fn json_schema(_: &mut $crate::schemars::SchemaGenerator) -> $crate::schemars::Schema {
+                    // a fallback variant takes any string, so the values are only listed as examples then
+                    if Self::fallback().is_some() {
+                        $crate::schemars::json_schema!({
+                            "type": "string",
+                            "examples": Self::EXPECTED_VALUES.as_slice(),
+                        })
+                    } else {
+                        $crate::schemars::json_schema!({
+                            "type": "string",
+                            "enum": Self::EXPECTED_VALUES.as_slice(),
+                        })
+                    }
+                }
+            }
+        });
+
+        $crate::str_enum_utoipa!({
+            impl $crate::utoipa::PartialSchema for $ty {
+                fn schema() -> $crate::utoipa::openapi::RefOr<$crate::utoipa::openapi::schema::Schema> {
+                    let schema = $crate::utoipa::openapi::schema::ObjectBuilder::new()
+                        .schema_type($crate::utoipa::openapi::schema::Type::String);
+                    // like the JsonSchema impl, a fallback variant takes any string
+                    if Self::fallback().is_some() {
+                        schema.examples(Self::EXPECTED_VALUES).into()
+                    } else {
+                        schema.enum_values(Some(Self::EXPECTED_VALUES)).into()
+                    }
+                }
+            }
+
+            impl $crate::utoipa::ToSchema for $ty {
+                fn name() -> $crate::alloc::borrow::Cow<'static, str> {
+                    $crate::alloc::borrow::Cow::Borrowed(stringify!($ty))
+                }
+            }
+        });
+
+        $crate::str_enum_clap!({
+            #[allow(deprecated)]
+            impl $crate::clap::ValueEnum for $ty {
+                fn value_variants<'a>() -> &'a [Self] {
+                    // clap expects a possible value for every variant it is given
+                    $crate::str_enum_base!(@clap_variants $ty $($variant)*)
+                }
+
+                #[allow(unreachable_code)] // enums without variants
+                fn to_possible_value(&self) -> Option<$crate::clap::builder::PossibleValue> {
+                    if self.parse_skipped() {
+                        return None;
+                    }
+                    // clap matches the value and aliases itself, so forms limited to deserializing are left out
+                    let aliases: &[(&str, $crate::AliasScope)] = match *self {
+                        $(Self::$variant => &[$($(($other_valid, $crate::str_enum_alias!(@scope $($alias_marker)*)),)*)?],)*
+                    };
+                    let value = $crate::clap::builder::PossibleValue::new(self.as_str())
+                        .aliases(aliases.iter().filter(|(_, scope)| scope.allows($crate::AliasScope::Parse)).map(|(alias, _)| *alias))
+                        .hide(self.is_deprecated());
+                    Some(match self.description() {
+                        "" => value,
+                        description => value.help(description),
+                    })
+                }
+            }
+        });
+
+        $crate::str_enum_rocket!({
+            impl<'a> $crate::rocket::request::FromParam<'a> for $ty {
+                type Error = &'a str;
+
+                fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+                    Self::try_from_str(param).ok_or(param)
+                }
+            }
+
+            impl<'v> $crate::rocket::form::FromFormField<'v> for $ty {
+                fn from_value(field: $crate::rocket::form::ValueField<'v>) -> $crate::rocket::form::Result<'v, Self> {
+                    Self::try_from_str(field.value).ok_or_else(|| {
+                        let choices: $crate::alloc::vec::Vec<$crate::alloc::borrow::Cow<'v, str>> =
+                            Self::EXPECTED_VALUES.iter().map(|value| $crate::alloc::borrow::Cow::Borrowed(*value)).collect();
+                        $crate::rocket::form::Error::from(choices)
+                            .with_name(field.name)
+                            .with_value(field.value)
+                            .into()
+                    })
+                }
+            }
+
+            impl<P: $crate::rocket::http::uri::fmt::Part> $crate::rocket::http::uri::fmt::UriDisplay<P> for $ty {
+                fn fmt(&self, f: &mut $crate::rocket::http::uri::fmt::Formatter<'_, P>) -> ::core::fmt::Result {
+                    f.write_value(self.as_str())
+                }
+            }
+
+            $crate::rocket::http::impl_from_uri_param_identity!($ty);
+        });
+
+        // a scalar rather than a GraphQL enum, whose names couldn't hold values like "light-blue"
+        $crate::str_enum_async_graphql!({
+            impl $crate::async_graphql::ScalarType for $ty {
+                fn parse(value: $crate::async_graphql::Value) -> $crate::async_graphql::InputValueResult<Self> {
+                    match &value {
+                        $crate::async_graphql::Value::String(s) => Self::try_from_str(s).ok_or_else(|| {
+                            $crate::async_graphql::InputValueError::custom($crate::alloc::format!(
+                                "invalid {}: expected one of {}",
+                                stringify!($ty),
+                                Self::EXPECTED_LIST
+                            ))
+                        }),
+                        _ => Err($crate::async_graphql::InputValueError::expected_type(value)),
+                    }
+                }
+
+                fn is_valid(value: &$crate::async_graphql::Value) -> bool {
+                    matches!(value, $crate::async_graphql::Value::String(s) if Self::try_from_str(s).is_some())
+                }
+
+                fn to_value(&self) -> $crate::async_graphql::Value {
+                    $crate::async_graphql::Value::String($crate::alloc::string::String::from(self.as_str()))
+                }
+            }
+
+            impl $crate::async_graphql::InputType for $ty {
+                type RawValueType = Self;
+
+                fn type_name() -> $crate::alloc::borrow::Cow<'static, str> {
+                    $crate::alloc::borrow::Cow::Borrowed(stringify!($ty))
+                }
+
+                fn create_type_info(registry: &mut $crate::async_graphql::registry::Registry) -> $crate::alloc::string::String {
+                    registry.create_input_type::<Self, _>($crate::async_graphql::registry::MetaTypeId::Scalar, |_| $crate::graphql_scalar_meta::<Self>(stringify!($ty), Self::EXPECTED_LIST))
+                }
+
+                fn parse(value: Option<$crate::async_graphql::Value>) -> $crate::async_graphql::InputValueResult<Self> {
+                    <Self as $crate::async_graphql::ScalarType>::parse(value.unwrap_or_default())
+                }
+
+                fn to_value(&self) -> $crate::async_graphql::Value {
+                    <Self as $crate::async_graphql::ScalarType>::to_value(self)
+                }
+
+                fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+                    Some(self)
+                }
+            }
+
+            impl $crate::async_graphql::OutputType for $ty {
+                fn type_name() -> $crate::alloc::borrow::Cow<'static, str> {
+                    $crate::alloc::borrow::Cow::Borrowed(stringify!($ty))
+                }
+
+                fn create_type_info(registry: &mut $crate::async_graphql::registry::Registry) -> $crate::alloc::string::String {
+                    registry.create_output_type::<Self, _>($crate::async_graphql::registry::MetaTypeId::Scalar, |_| $crate::graphql_scalar_meta::<Self>(stringify!($ty), Self::EXPECTED_LIST))
+                }
+
+                async fn resolve(
+                    &self,
+                    _: &$crate::async_graphql::ContextSelectionSet<'_>,
+                    _: &$crate::async_graphql::Positioned<$crate::async_graphql::parser::types::Field>,
+                ) -> $crate::async_graphql::ServerResult<$crate::async_graphql::Value> {
+                    Ok(<Self as $crate::async_graphql::ScalarType>::to_value(self))
+                }
+            }
+        });
+
+        // like the async-graphql impls, a string scalar over the value
+        $crate::str_enum_juniper!({
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::GraphQLType<S> for $ty {
+                fn name(_: &()) -> Option<&'static str> {
+                    Some(stringify!($ty))
+                }
+
+                fn meta<'r>(info: &(), registry: &mut $crate::juniper::Registry<'r, S>) -> $crate::juniper::meta::MetaType<'r, S>
+                where
+                    S: 'r,
+                {
+                    registry
+                        .build_scalar_type::<Self>(info)
+                        .description(&$crate::alloc::format!("One of {}", Self::EXPECTED_LIST))
+                        .into_meta()
+                }
+            }
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::GraphQLValue<S> for $ty {
+                type Context = ();
+                type TypeInfo = ();
+
+                fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+                    <Self as $crate::juniper::GraphQLType<S>>::name(info)
+                }
+
+                fn resolve(
+                    &self,
+                    _: &(),
+                    _: Option<&[$crate::juniper::Selection<'_, S>]>,
+                    _: &$crate::juniper::Executor<'_, '_, (), S>,
+                ) -> $crate::juniper::ExecutionResult<S> {
+                    Ok($crate::juniper::Value::scalar($crate::alloc::string::String::from(self.as_str())))
+                }
+            }
+
+            impl<S> $crate::juniper::GraphQLValueAsync<S> for $ty
+            where
+                S: $crate::juniper::ScalarValue + Send + Sync,
+                Self: Sync,
+            {
+                fn resolve_async<'b>(
+                    &'b self,
+                    info: &'b (),
+                    selection_set: Option<&'b [$crate::juniper::Selection<'_, S>]>,
+                    executor: &'b $crate::juniper::Executor<'_, '_, (), S>,
+                ) -> $crate::juniper::BoxFuture<'b, $crate::juniper::ExecutionResult<S>> {
+                    let value = $crate::juniper::GraphQLValue::resolve(self, info, selection_set, executor);
+                    $crate::alloc::boxed::Box::pin($crate::juniper::futures::future::ready(value))
+                }
+            }
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::ToInputValue<S> for $ty {
+                fn to_input_value(&self) -> $crate::juniper::InputValue<S> {
+                    $crate::juniper::InputValue::scalar($crate::alloc::string::String::from(self.as_str()))
+                }
+            }
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::FromInputValue<S> for $ty {
+                type Error = $crate::juniper::FieldError<S>;
+
+                fn from_input_value(value: &$crate::juniper::InputValue<S>) -> Result<Self, Self::Error> {
+                    let s = value
+                        .as_string_value()
+                        .ok_or_else(|| $crate::alloc::format!("Expected `String`, found: {value}"))?;
+                    Self::try_from_str(s).ok_or_else(|| {
+                        $crate::alloc::format!("invalid {}: expected one of {}", stringify!($ty), Self::EXPECTED_LIST).into()
+                    })
+                }
+            }
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::ParseScalarValue<S> for $ty {
+                // literals are unescaped like any string, then checked by `from_input_value`
+                fn from_str(value: $crate::juniper::parser::ScalarToken<'_>) -> $crate::juniper::ParseScalarResult<S> {
+                    <$crate::alloc::string::String as $crate::juniper::ParseScalarValue<S>>::from_str(value)
+                }
+            }
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::marker::IsInputType<S> for $ty {}
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::marker::IsOutputType<S> for $ty {}
+
+            // what `#[derive(GraphQLScalar)]` implements for juniper's own macros to check field types
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::macros::reflect::BaseType<S> for $ty {
+                const NAME: $crate::juniper::macros::reflect::Type = stringify!($ty);
+            }
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::macros::reflect::BaseSubTypes<S> for $ty {
+                const NAMES: $crate::juniper::macros::reflect::Types = &[stringify!($ty)];
+            }
+
+            impl<S: $crate::juniper::ScalarValue> $crate::juniper::macros::reflect::WrappedType<S> for $ty {
+                const VALUE: $crate::juniper::macros::reflect::WrappedValue = 1;
+            }
+        });
+
+        $crate::str_enum_http!($(#[http $($http)*])? {
+            $crate::str_enum_base!(@http_error_type [$($error_ty)?]);
+
+            const _: () = assert!(
+                $crate::all_header_values($ty::ALL_VALUES),
+                concat!("every value of `", stringify!($ty), "` must be a valid header value, without control characters")
+            );
+
+            impl From<$ty> for $crate::http::HeaderValue {
+                fn from(value: $ty) -> Self {
+                    $crate::header_value(value.as_str())
+                }
+            }
+
+            impl From<&$ty> for $crate::http::HeaderValue {
+                fn from(value: &$ty) -> Self {
+                    $crate::header_value(value.as_str())
+                }
+            }
+
+            $(
+                impl TryFrom<&$crate::http::HeaderValue> for $ty {
+                    type Error = $crate::Utf8EnumError<$error_ty>;
+
+                    fn try_from(value: &$crate::http::HeaderValue) -> Result<$ty, Self::Error> {
+                        $ty::try_from(value.as_bytes())
+                    }
+                }
+
+                impl TryFrom<$crate::http::HeaderValue> for $ty {
+                    type Error = $crate::Utf8EnumError<$error_ty>;
+
+                    fn try_from(value: $crate::http::HeaderValue) -> Result<$ty, Self::Error> {
+                        $ty::try_from(&value)
+                    }
+                }
+            )?
+        });
+
+        // generic over the database, so each driver stores it however it stores `str` (TEXT, VARCHAR, ...)
+        $crate::str_enum_sqlx!({
+            impl<DB: $crate::sqlx::Database> $crate::sqlx::Type<DB> for $ty
+            where
+                str: $crate::sqlx::Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <str as $crate::sqlx::Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool {
+                    <str as $crate::sqlx::Type<DB>>::compatible(ty)
+                }
+            }
+
+            impl<'q, DB: $crate::sqlx::Database> $crate::sqlx::Encode<'q, DB> for $ty
+            where
+                &'q str: $crate::sqlx::Encode<'q, DB>,
+            {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <DB as $crate::sqlx::Database>::ArgumentBuffer<'q>,
+                ) -> Result<$crate::sqlx::encode::IsNull, $crate::sqlx::error::BoxDynError> {
+                    <&'q str as $crate::sqlx::Encode<'q, DB>>::encode_by_ref(&self.as_str(), buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    <&'q str as $crate::sqlx::Encode<'q, DB>>::size_hint(&self.as_str())
+                }
+            }
+
+            impl<'r, DB: $crate::sqlx::Database> $crate::sqlx::Decode<'r, DB> for $ty
+            where
+                &'r str: $crate::sqlx::Decode<'r, DB>,
+            {
+                fn decode(value: <DB as $crate::sqlx::Database>::ValueRef<'r>) -> Result<Self, $crate::sqlx::error::BoxDynError> {
+                    let value = <&'r str as $crate::sqlx::Decode<'r, DB>>::decode(value)?;
+                    Self::try_from_str(value).ok_or_else(|| $crate::str_enum_base!(@sqlx_decode_error $ty [$($error_ty)?] value).into())
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped FromIterator [$($($skip_impl)*)?] {
+            $crate::str_enum_base!(FromIterator $ty, [$crate::alloc::boxed::Box<str>, $crate::alloc::string::String]);
+            $crate::str_enum_base!(FromIterator 'a $ty, [$crate::alloc::borrow::Cow<'a, str>]);
+
+            impl ::core::iter::FromIterator<$ty> for $crate::alloc::vec::Vec<u8> {
+                fn from_iter<T>(iter: T) -> $crate::alloc::vec::Vec<u8>
+                where
+                    T: IntoIterator<Item = $ty>
+                {
+                    iter.into_iter().flat_map(|s| s.as_str().as_bytes()).copied().collect()
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped Index [$($($skip_impl)*)?] {
+            impl<I: ::core::slice::SliceIndex<str>> ::core::ops::Index<I> for $ty {
+                type Output = <I as ::core::slice::SliceIndex<str>>::Output;
+
+                fn index(&self, index: I) -> &<I as ::core::slice::SliceIndex<str>>::Output {
+                    self.as_str().index(index)
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped PartialEq [$($($skip_impl)*)?] {
+            $crate::str_enum_base!(PartialEq $ty, [$crate::alloc::string::String]);
+            $crate::str_enum_base!(PartialEq 'a $ty, [$crate::alloc::borrow::Cow<'a, str>]);
+            $crate::str_enum_base!(PartialEq &$ty, [str, $crate::alloc::string::String]);
+            $crate::str_enum_base!(PartialEq 'a &$ty, [$crate::alloc::borrow::Cow<'a, str>]);
+
+            impl PartialEq<&str> for $ty {
+                fn eq(&self, rhs: &&str) -> bool {
+                    self.as_str().eq(*rhs)
+                }
+            }
+
+            impl PartialEq<$ty> for &str {
+                fn eq(&self, rhs: &$ty) -> bool {
+                    self.eq(&rhs.as_str())
+                }
+            }
+
+            impl PartialEq<str> for $ty {
+                fn eq(&self, rhs: &str) -> bool {
+                    self.as_str().eq(rhs)
+                }
+            }
+
+            impl PartialEq<$ty> for str {
+                fn eq(&self, rhs: &$ty) -> bool {
+                    self.eq(rhs.as_str())
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped PartialOrd [$($($skip_impl)*)?] {
+            impl PartialOrd<$ty> for str {
+                fn partial_cmp(&self, rhs: &$ty) -> Option<::core::cmp::Ordering> {
+                    self.partial_cmp(rhs.as_str())
+                }
+            }
+
+            impl PartialOrd<str> for $ty {
+                fn partial_cmp(&self, rhs: &str) -> Option<::core::cmp::Ordering> {
+                    self.as_str().partial_cmp(rhs)
+                }
+            }
+
+            impl PartialOrd<$ty> for &str {
+                fn partial_cmp(&self, rhs: &$ty) -> Option<::core::cmp::Ordering> {
+                    self.partial_cmp(&rhs.as_str())
+                }
+            }
+
+            impl PartialOrd<&str> for $ty {
+                fn partial_cmp(&self, rhs: &&str) -> Option<::core::cmp::Ordering> {
+                    self.as_str().partial_cmp(*rhs)
+                }
+            }
+        });
+    };
+    // defines a macro with the same name as the enum, so `#[include(Enum)]` on a later str_enum can get at the variants
+    (@include_macro ($d:tt) $ty:ident [$($variant:ident)*] { $($variants:tt)* } { $([$($parse_opt:ident)*] [$spelled:ident $($val:literal)? ($($alias:tt)*)])* }) => {
+        #[allow(unused_macros)]
+        macro_rules! $ty {
+            (pattern) => {
+                $crate::str_enum_base!(@pattern $ty [] { $([$($parse_opt)*] [$spelled $($val)? ($($alias)*)])* })
+            };
+            $(
+                (pattern $spelled) => {
+                    $crate::str_enum_base!(@pattern $ty [] { [$($parse_opt)*] [$spelled $($val)? ($($alias)*)] })
+                };
+            )*
+            (@str_enum_include [$d($d head:tt)*] $d extended:ident { $d($d body:tt)* }) => {
+                $crate::str_enum! {
+                    $d($d head)* enum $d extended {
+                        $($variants)*
+                        $d($d body)*
+                    }
+                }
+
+                $crate::str_enum_base!(@include_conversions $ty $d extended [$($variant)*]);
+            };
+        }
+    };
+    (@default $ty:ident []) => {};
+    // `$default` is the empty visibility matched after `#[default]`, there to tell whether the attribute was there
+    (@default $ty:ident [$default:vis $variant:ident]) => {
+        #[allow(deprecated)]
+        impl Default for $ty {
+            fn default() -> Self {
+                Self::$variant
+            }
+        }
+    };
+    (@default $ty:ident [$($default:vis $variant:ident)*]) => {
+        compile_error!("only one variant can be marked `#[default]`");
+    };
+    // the spellings `try_from_str` accepts as a pattern, for `MyEnum!(pattern)`: `#[parse(skip)]` variants and `serde` alternate forms are left out
+    (@pattern $ty:ident [] {}) => {
+        compile_error!(concat!("`", stringify!($ty), "` has no values to match"))
+    };
+    (@pattern $ty:ident [$($spellings:tt)+] {}) => {
+        $($crate::str_enum_base!(@pattern_spellings $ty $spellings))|+
+    };
+    // variants without `#[parse]` options are taken eight at a time, so enums with hundreds of variants stay under the recursion limit
+    (@pattern $ty:ident [$($spellings:tt)*] { [] $a:tt [] $b:tt [] $c:tt [] $d:tt [] $e:tt [] $f:tt [] $g:tt [] $h:tt $($rest:tt)* }) => {
+        $crate::str_enum_base!(@pattern $ty [$($spellings)* $a $b $c $d $e $f $g $h] { $($rest)* })
+    };
+    (@pattern $ty:ident $spellings:tt { [skip $($opt:ident)*] [$($variant:tt)*] $($rest:tt)* }) => {
+        $crate::str_enum_base!(@pattern $ty $spellings { $($rest)* })
+    };
+    (@pattern $ty:ident $spellings:tt { [$other:ident $($opt:ident)*] [$($variant:tt)*] $($rest:tt)* }) => {
+        $crate::str_enum_base!(@pattern $ty $spellings { [$($opt)*] [$($variant)*] $($rest)* })
+    };
+    (@pattern $ty:ident [$($spellings:tt)*] { [] [$($variant:tt)*] $($rest:tt)* }) => {
+        $crate::str_enum_base!(@pattern $ty [$($spellings)* [$($variant)*]] { $($rest)* })
+    };
+    // one variant's value and the alternate forms accepted when parsing
+    (@pattern_spellings $ty:ident [$variant:ident $($val:literal)? ($($alias:tt)*)]) => {
+        $crate::str_enum_base!(@pattern_aliases [[$crate::str_enum_base!(@pattern_value $ty $variant $($val)?)]] { $($alias)* })
+    };
+    (@pattern_aliases [$([$($kept:tt)*])+] {}) => {
+        $($($kept)*)|+
+    };
+    (@pattern_aliases $kept:tt { [serde $($marker:ident)*] $alias:literal $($rest:tt)* }) => {
+        $crate::str_enum_base!(@pattern_aliases $kept { $($rest)* })
+    };
+    (@pattern_aliases $kept:tt { [$other:ident $($marker:ident)*] $alias:literal $($rest:tt)* }) => {
+        $crate::str_enum_base!(@pattern_aliases $kept { [$($marker)*] $alias $($rest)* })
+    };
+    (@pattern_aliases [$($kept:tt)*] { [] $alias:literal $($rest:tt)* }) => {
+        $crate::str_enum_base!(@pattern_aliases [$($kept)* [$alias]] { $($rest)* })
+    };
+    (@pattern_value $ty:ident $variant:ident $val:literal) => {
+        $val
+    };
+    // a pattern can't call `as_str`, so values left to the variant's name are matched through the constant of `@variant_value`
+    (@pattern_value $ty:ident $variant:ident) => {
+        <$ty as $crate::VariantValue<{ $crate::variant_index($ty::VARIANT_NAMES, stringify!($variant)) }>>::VALUE
+    };
+    (@variant_value $ty:ident $variant:ident $val:literal) => {};
+    (@variant_value $ty:ident $variant:ident) => {
+        impl $crate::VariantValue<{ $crate::variant_index($ty::VARIANT_NAMES, stringify!($variant)) }> for $ty {
+            const VALUE: &'static str = $ty::ALL_VALUES[$crate::variant_index($ty::VARIANT_NAMES, stringify!($variant))];
+        }
+    };
+    (@include_conversions $base:ident $extended:ident [$($variant:ident)*]) => {
+        #[allow(deprecated)]
+        impl From<$base> for $extended {
+            fn from(variant: $base) -> $extended {
+                match variant {
+                    $($base::$variant => $extended::$variant,)*
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl TryFrom<$extended> for $base {
+            type Error = $extended;
+
+            #[allow(unreachable_patterns)]
+            fn try_from(variant: $extended) -> Result<$base, $extended> {
+                match variant {
+                    $($extended::$variant => Ok($base::$variant),)*
+                    other => Err(other),
+                }
+            }
+        }
+    };
+    (@error_struct $vis:vis $error_ty:ident [$($capture:tt)*] [$($derive:tt)*] [capture_input $($rest:tt)*]) => {
+        $crate::str_enum_base!(@error_struct $vis $error_ty [capture_input] [$($derive)*] [$($rest)*]);
+    };
+    (@error_struct $vis:vis $error_ty:ident [$($capture:tt)*] [$($derive:tt)*] [derive($($trait:path),* $(,)?) $($rest:tt)*]) => {
+        $crate::str_enum_base!(@error_struct $vis $error_ty [$($capture)*] [$($derive)* $($trait,)*] [$($rest)*]);
+    };
+    (@error_struct $vis:vis $error_ty:ident [] [$($derive:path,)*] []) => {
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, $($derive),*)]
+        $vis struct $error_ty;
+
+        impl $error_ty {
+            #[doc = "Builds the error for `input` failing to parse. You do not need this."]
+            fn from_input(_input: impl ::core::fmt::Display) -> Self {
+                Self
+            }
+
+            #[doc = "The input that failed to parse, always `None` without `capture_input`. You do not need this."]
+            const fn captured_input(&self) -> Option<&str> {
+                None
+            }
+        }
+
+        $crate::str_enum_miette!({
+            impl $error_ty {
+                #[doc = "The input as miette source code, always `None` without `capture_input`. You do not need this."]
+                fn captured_source(&self) -> Option<&dyn $crate::miette::SourceCode> {
+                    None
+                }
+            }
+        });
+    };
+    (@error_struct $vis:vis $error_ty:ident [capture_input] [$($derive:path,)*] []) => {
+        #[doc = "The input that matched none of the values"]
+        #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, $($derive),*)]
+        $vis struct $error_ty(pub $crate::alloc::string::String);
+
+        impl $error_ty {
+            #[doc = "Builds the error for `input` failing to parse. You do not need this."]
+            fn from_input(input: impl ::core::fmt::Display) -> Self {
+                Self($crate::alloc::string::ToString::to_string(&input))
+            }
+
+            #[doc = "The input that failed to parse. You do not need this."]
+            const fn captured_input(&self) -> Option<&str> {
+                Some(self.0.as_str())
+            }
+
+            #[doc = "The input that failed to parse"]
+            pub fn input(&self) -> &str {
+                &self.0
+            }
+        }
+
+        $crate::str_enum_miette!({
+            impl $error_ty {
+                #[doc = "The input as miette source code. You do not need this."]
+                fn captured_source(&self) -> Option<&dyn $crate::miette::SourceCode> {
+                    Some(&self.0)
+                }
+            }
+        });
+    };
+    (@error_struct $vis:vis $error_ty:ident [$($capture:tt)*] [$($derive:tt)*] [message $message:tt $($rest:tt)*]) => {
+        $crate::str_enum_base!(@error_struct $vis $error_ty [$($capture)*] [$($derive)*] [$($rest)*]);
+    };
+    (@error_struct $vis:vis $error_ty:ident [$($capture:tt)*] [$($derive:tt)*] [$opt:ident $($rest:tt)*]) => {
+        compile_error!(concat!("unknown error_type option `", stringify!($opt), "`, expected `capture_input`, `derive(...)` or `message(\"...\")`"));
+    };
+    (@sqlx_decode_error $ty:ident [] $input:ident) => {
+        $crate::alloc::format!("invalid {}: expected one of {}", stringify!($ty), $ty::EXPECTED_LIST)
+    };
+    (@sqlx_decode_error $ty:ident [$error_ty:ident] $input:ident) => {
+        $error_ty::from_input($input)
+    };
+    (@error_template $ty:ident []) => {
+        concat!("invalid ", stringify!($ty), ": expected one of {values}")
+    };
+    (@error_template $ty:ident [message($message:literal) $($rest:tt)*]) => {
+        $message
+    };
+    (@error_template $ty:ident [$opt:ident $($rest:tt)*]) => {
+        $crate::str_enum_base!(@error_template $ty [$($rest)*])
+    };
+    (@error_template $ty:ident [$arg:tt $($rest:tt)*]) => {
+        $crate::str_enum_base!(@error_template $ty [$($rest)*])
+    };
+    (@http_error_type []) => {
+        compile_error!("`#[http]` needs `#[error_type(...)]`, the error of converting back from a `HeaderValue`");
+    };
+    (@http_error_type [$error_ty:ident]) => {};
+    // impl groups `#[skip_impls(...)]` can leave out, each arm checks one group against the start of the list
+    (@unless_skipped $group:ident [] $impls:tt) => {
+        $crate::str_enum_base!(@tier $group $impls);
+    };
+    (@unless_skipped Add [Add $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped AsRef [AsRef $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped Borrow [Borrow $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped Display [Display $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped Extend [Extend $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped From [From $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped IntoStaticStr [IntoStaticStr $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped FromIterator [FromIterator $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped Hash [Hash $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped Index [Index $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped PartialEq [PartialEq $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped PartialOrd [PartialOrd $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped ToSocketAddrs [ToSocketAddrs $($rest:ident)*] $impls:tt) => {};
+    (@unless_skipped $group:ident [$skipped:ident $($rest:ident)*] $impls:tt) => {
+        $crate::str_enum_base!(@unless_skipped $group [$($rest)*] $impls);
+    };
+    // groups outside the core string API are left out when their cargo feature is off
+    (@tier From $impls:tt) => {
+        $crate::str_enum_conversions!($impls);
+    };
+    (@tier IntoStaticStr $impls:tt) => {
+        $crate::str_enum_into_static_str!($impls);
+    };
+    (@tier $group:tt $impls:tt) => {
+        $crate::str_enum_extras!($group $impls);
+    };
+    // `#[cmp(str)]` compares variants by their value, agreeing with the `Hash` and `Borrow<str>` impls
+    (@cmp $ty:ident str) => {
+        impl PartialEq for $ty {
+            fn eq(&self, rhs: &Self) -> bool {
+                self.as_str() == rhs.as_str()
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $ty {
+            fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                self.as_str().cmp(rhs.as_str())
+            }
+        }
+    };
+    (@cmp $ty:ident $other:ident) => {
+        compile_error!(concat!("unknown `#[cmp]` mode `", stringify!($other), "`, expected `str`"));
+    };
+    (@check_skip_impls []) => {};
+    (@check_skip_impls [Add $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [AsRef $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [Borrow $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [Display $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [Extend $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [From $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [FromIterator $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [IntoStaticStr $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [Hash $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [Index $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [PartialEq $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [PartialOrd $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [ToSocketAddrs $($rest:ident)*]) => {
+        $crate::str_enum_base!(@check_skip_impls [$($rest)*]);
+    };
+    (@check_skip_impls [$group:ident $($rest:ident)*]) => {
+        compile_error!(concat!("unknown impl group `", stringify!($group), "` in `#[skip_impls]`, expected one of `Add`, `AsRef`, `Borrow`, `Display`, `Extend`, `From`, `FromIterator`, `Hash`, `Index`, `IntoStaticStr`, `PartialEq`, `PartialOrd` or `ToSocketAddrs`"));
+    };
+    (@from_repr $ty:ident, [], $error_ty:tt, $variants:tt) => {};
+    (@from_repr $ty:ident, [$repr:ty], [$($error_ty:ident)?], { $($variant:ident,)* }) => {
+        #[allow(deprecated)]
+        impl $ty {
+            #[doc = "The variant with `repr` as its discriminant, if there is one"]
+            pub const fn from_repr(repr: $repr) -> Option<Self> {
+                $(
+                    if repr == $ty::$variant as $repr {
+                        return Some($ty::$variant);
+                    }
+                )*
+                None
+            }
+        }
+
+        $(
+            impl TryFrom<$repr> for $ty {
+                type Error = $error_ty;
+
+                fn try_from(repr: $repr) -> Result<Self, Self::Error> {
+                    $ty::from_repr(repr).ok_or_else(|| $error_ty::from_input(repr))
+                }
+            }
+        )?
+    };
+    (StrType $vis:vis $ty:ident, [], $variants:tt) => {};
+    (StrType $vis:vis $ty:ident, [$str_type:ident], { $($variant:ident,)* }) => {
+        #[doc = concat!("A `&'static str` that is known to be a value of [`", stringify!($ty), "`]")]
+        #[derive(Clone, Copy)]
+        $vis struct $str_type(&'static str, &'static $ty);
+
+        #[allow(deprecated)]
+        impl $str_type {
+            #[doc = "The value of `variant`"]
+            pub const fn from_variant(variant: &$ty) -> Self {
+                Self(variant.as_str(), &$ty::ALL_VARIANTS[variant.index()])
+            }
+
+            #[doc = concat!("Parses `s` with `", stringify!($ty), "::try_from_str`, holding the canonical value of the variant it matched")]
+            pub fn new(s: &str) -> Option<Self> {
+                $ty::try_from_str(s).map(|variant| Self::from_variant(&variant))
+            }
+
+            pub const fn as_str(&self) -> &'static str {
+                self.0
+            }
+
+            #[doc = "The variant this is the value of"]
+            pub const fn to_variant(self) -> $ty {
+                match *self.1 {
+                    $($ty::$variant => $ty::$variant,)*
+                }
+            }
+        }
+
+        impl ::core::fmt::Debug for $str_type {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                fmt.debug_tuple(stringify!($str_type)).field(&self.0).finish()
+            }
+        }
+
+        // compared and hashed by the value alone, as `Borrow<str>` needs
+        impl PartialEq for $str_type {
+            fn eq(&self, rhs: &Self) -> bool {
+                self.0.eq(rhs.0)
+            }
+        }
+
+        impl Eq for $str_type {}
+
+        impl PartialOrd for $str_type {
+            fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $str_type {
+            fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                self.0.cmp(rhs.0)
+            }
+        }
+
+        impl ::core::hash::Hash for $str_type {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        impl From<$ty> for $str_type {
+            fn from(variant: $ty) -> $str_type {
+                $str_type::from_variant(&variant)
+            }
+        }
+
+        impl From<&$ty> for $str_type {
+            fn from(variant: &$ty) -> $str_type {
+                $str_type::from_variant(variant)
+            }
+        }
+
+        impl From<$str_type> for $ty {
+            fn from(s: $str_type) -> $ty {
+                s.to_variant()
+            }
+        }
+
+        impl From<$str_type> for &'static str {
+            fn from(s: $str_type) -> &'static str {
+                s.0
+            }
+        }
+
+        impl ::core::ops::Deref for $str_type {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                self.0
+            }
+        }
+
+        impl AsRef<str> for $str_type {
+            fn as_ref(&self) -> &str {
+                self.0
+            }
+        }
+
+        impl ::core::borrow::Borrow<str> for $str_type {
+            fn borrow(&self) -> &str {
+                self.0
+            }
+        }
+
+        impl ::core::fmt::Display for $str_type {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                <str as ::core::fmt::Display>::fmt(self.0, fmt)
+            }
+        }
+
+        impl PartialEq<str> for $str_type {
+            fn eq(&self, rhs: &str) -> bool {
+                self.0.eq(rhs)
+            }
+        }
+
+        impl PartialEq<&str> for $str_type {
+            fn eq(&self, rhs: &&str) -> bool {
+                self.0.eq(*rhs)
+            }
+        }
+
+        impl PartialEq<$ty> for $str_type {
+            fn eq(&self, rhs: &$ty) -> bool {
+                self.0.eq(rhs.as_str())
+            }
+        }
+    };
+    (CatchAll $vis:vis $ty:ident, [], $variants:tt) => {};
+    (CatchAll $vis:vis $ty:ident, [$catch_all:ident], { $($variant:ident,)* }) => {
+        #[doc = concat!("Either a [`", stringify!($ty), "`] or any other string, for values that may come from a newer or looser producer")]
+        $vis enum $catch_all {
+            Known($ty),
+            Other($crate::alloc::string::String),
+        }
+
+        impl $catch_all {
+            #[doc = concat!("Parses `s` with `", stringify!($ty), "::try_from_str`, keeping it as `Other` when it matches no variant")]
+            pub fn new(s: &str) -> Self {
+                match $ty::try_from_str(s) {
+                    Some(known) => Self::Known(known),
+                    None => Self::Other(s.into()),
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Known(known) => known.as_str(),
+                    Self::Other(other) => other.as_str(),
+                }
+            }
+
+            #[doc = "The variant, if this is one"]
+            pub const fn known(&self) -> Option<&$ty> {
+                match self {
+                    Self::Known(known) => Some(known),
+                    Self::Other(_) => None,
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl Clone for $catch_all {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::Known(known) => match *known {
+                        $($ty::$variant => Self::Known($ty::$variant),)*
+                    },
+                    Self::Other(other) => Self::Other(other.clone()),
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Debug for $catch_all {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    Self::Known(known) => match *known {
+                        $($ty::$variant => fmt.debug_tuple("Known").field(&format_args!(stringify!($variant))).finish(),)*
+                    },
+                    Self::Other(other) => fmt.debug_tuple("Other").field(other).finish(),
+                }
+            }
+        }
+
+        impl PartialEq for $catch_all {
+            fn eq(&self, rhs: &Self) -> bool {
+                self.as_str() == rhs.as_str()
+            }
+        }
+
+        impl Eq for $catch_all {}
+
+        impl PartialOrd for $catch_all {
+            fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+
+        impl Ord for $catch_all {
+            fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                self.as_str().cmp(rhs.as_str())
+            }
+        }
+
+        impl ::core::hash::Hash for $catch_all {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state)
+            }
+        }
+
+        impl PartialEq<str> for $catch_all {
+            fn eq(&self, rhs: &str) -> bool {
+                self.as_str().eq(rhs)
+            }
+        }
+
+        impl PartialEq<&str> for $catch_all {
+            fn eq(&self, rhs: &&str) -> bool {
+                self.as_str().eq(*rhs)
+            }
+        }
+
+        impl PartialEq<$ty> for $catch_all {
+            fn eq(&self, rhs: &$ty) -> bool {
+                self.as_str().eq(rhs.as_str())
+            }
+        }
+
+        impl From<$ty> for $catch_all {
+            fn from(known: $ty) -> $catch_all {
+                $catch_all::Known(known)
+            }
+        }
+
+        impl From<&str> for $catch_all {
+            fn from(s: &str) -> $catch_all {
+                $catch_all::new(s)
+            }
+        }
+
+        impl From<$crate::alloc::string::String> for $catch_all {
+            fn from(s: $crate::alloc::string::String) -> $catch_all {
+                match $ty::try_from_str(&s) {
+                    Some(known) => $catch_all::Known(known),
+                    None => $catch_all::Other(s),
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $catch_all {
+            type Err = ::core::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::new(s))
+            }
+        }
+
+        impl ::core::fmt::Display for $catch_all {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                <str as ::core::fmt::Display>::fmt(self.as_str(), fmt)
+            }
+        }
+
+        impl AsRef<str> for $catch_all {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::borrow::Borrow<str> for $catch_all {
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
+        }
+    };
+    (EnumMap $vis:vis $ty:ident, [], $variants:tt) => {};
+    (EnumMap $vis:vis $ty:ident, [$enum_map:ident], { $($variant:ident,)* }) => {
+        #[doc = concat!("A `T` for every variant of [`", stringify!($ty), "`], stored in declaration order")]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $enum_map<T>([T; $ty::NUM_VARIANTS]);
+
+        impl<T> $enum_map<T> {
+            #[doc = "Builds the map by calling `f` with each variant, in declaration order"]
+            pub fn from_fn(mut f: impl FnMut($ty) -> T) -> Self {
+                Self($ty::VARIANT_ARRAY.map(f))
+            }
+
+            pub const fn get(&self, variant: &$ty) -> &T {
+                &self.0[variant.index()]
+            }
+
+            pub const fn get_mut(&mut self, variant: &$ty) -> &mut T {
+                &mut self.0[variant.index()]
+            }
+
+            #[doc = "Every variant with its value, in declaration order"]
+            pub fn iter(&self) -> impl Iterator<Item = ($ty, &T)> {
+                $ty::iter().zip(self.0.iter())
+            }
+
+            pub fn iter_mut(&mut self) -> impl Iterator<Item = ($ty, &mut T)> {
+                $ty::iter().zip(self.0.iter_mut())
+            }
+
+            pub fn values(&self) -> ::core::slice::Iter<'_, T> {
+                self.0.iter()
+            }
+
+            pub fn values_mut(&mut self) -> ::core::slice::IterMut<'_, T> {
+                self.0.iter_mut()
+            }
+
+            pub fn into_array(self) -> [T; $ty::NUM_VARIANTS] {
+                self.0
+            }
+        }
+
+        impl<T: Default> Default for $enum_map<T> {
+            fn default() -> Self {
+                Self::from_fn(|_| T::default())
+            }
+        }
+
+        impl<T: ::core::fmt::Debug> ::core::fmt::Debug for $enum_map<T> {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                fmt.debug_map().entries($ty::ALL_VALUES.iter().zip(self.0.iter())).finish()
+            }
+        }
+
+        impl<T> ::core::ops::Index<$ty> for $enum_map<T> {
+            type Output = T;
+
+            fn index(&self, variant: $ty) -> &T {
+                self.get(&variant)
+            }
+        }
+
+        impl<T> ::core::ops::IndexMut<$ty> for $enum_map<T> {
+            fn index_mut(&mut self, variant: $ty) -> &mut T {
+                self.get_mut(&variant)
+            }
+        }
+    };
+    (@constant $ty:ident [] { $($variant:ident,)* }) => {};
+    (@constant $ty:ident [] $variants:tt) => {
+        compile_error!("a `: constant` after a variant's value needs `#[constant(name: Type)]` on the enum");
+    };
+    (@constant $ty:ident [$const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)?] { $($variant:ident: $variant_const:literal,)* }) => {
+        #[allow(deprecated)]
+        impl $ty {
+            #[doc = "The constant written after this variant's value"]
+            pub const fn $const_fn(&self) -> $const_ty {
+                match *self {
+                    $(Self::$variant => $variant_const,)*
+                }
+            }
+
+            $crate::str_enum_base!(@constant_from [$($const_from_fn)?] $const_ty { $($variant: $variant_const,)* });
+        }
+    };
+    (@constant_from [] $const_ty:ty { $($variants:tt)* }) => {};
+    (@constant_from [$const_from_fn:ident] $const_ty:ty { $($variant:ident: $variant_const:literal,)* }) => {
+        #[doc = "The first variant whose constant equals `value`"]
+        pub fn $const_from_fn(value: $const_ty) -> Option<Self> {
+            $(
+                if value == $variant_const {
+                    return Some(Self::$variant);
+                }
+            )*
+            None
+        }
+    };
+    (@constant $ty:ident [$($constant:tt)*] $variants:tt) => {
+        compile_error!("every variant needs a `: constant` after its value when the enum has `#[constant]`");
+    };
+    (EnumSet $vis:vis $ty:ident, [], $variants:tt) => {};
+    (EnumSet $vis:vis $ty:ident, [$enum_set:ident], { $($variant:ident,)* }) => {
+        #[doc = concat!("A set of [`", stringify!($ty), "`] variants, one bit each in the smallest integer that fits them all")]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $enum_set(<$crate::SetWidth<{ $ty::NUM_VARIANTS }> as $crate::SetRepr>::Bits);
+
+        impl $enum_set {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #[doc = "A set of every variant"]
+            pub fn all() -> Self {
+                $ty::iter().collect()
+            }
+
+            #[doc = "Adds `variant`, returning whether it wasn't in the set yet"]
+            pub fn insert(&mut self, variant: $ty) -> bool {
+                let inserted = !self.contains(&variant);
+                self.0 |= Self::bit(&variant);
+                inserted
+            }
+
+            #[doc = "Removes `variant`, returning whether it was in the set"]
+            pub fn remove(&mut self, variant: &$ty) -> bool {
+                let removed = self.contains(variant);
+                self.0 &= !Self::bit(variant);
+                removed
+            }
+
+            pub fn contains(&self, variant: &$ty) -> bool {
+                self.0 & Self::bit(variant) != Self::default().0
+            }
+
+            pub fn len(&self) -> usize {
+                $crate::SetBits::count(self.0) as usize
+            }
+
+            pub fn is_empty(&self) -> bool {
+                *self == Self::default()
+            }
+
+            #[doc = "The variants in the set, in declaration order"]
+            pub fn iter(&self) -> impl Iterator<Item = $ty> + use<> {
+                let set = *self;
+                $ty::iter().filter(move |variant| set.contains(variant))
+            }
+
+            #[doc = "The underlying bits, where declaration order `n` is bit `n`"]
+            pub fn bits(&self) -> <$crate::SetWidth<{ $ty::NUM_VARIANTS }> as $crate::SetRepr>::Bits {
+                self.0
+            }
+
+            fn bit(variant: &$ty) -> <$crate::SetWidth<{ $ty::NUM_VARIANTS }> as $crate::SetRepr>::Bits {
+                $crate::SetBits::bit(variant.index())
+            }
+        }
+
+        impl ::core::fmt::Debug for $enum_set {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                fmt.debug_set().entries(self.iter().map(|variant| variant.as_str())).finish()
+            }
+        }
+
+        #[doc = "The values of the variants in the set, separated by `,`"]
+        impl ::core::fmt::Display for $enum_set {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                for (idx, variant) in self.iter().enumerate() {
+                    if idx != 0 {
+                        fmt.write_str(",")?;
+                    }
+                    fmt.write_str(variant.as_str())?;
+                }
+                Ok(())
+            }
+        }
+
+        #[doc = concat!("Parses `,` separated values with `", stringify!($ty), "::try_from_str`, like `parse_list`")]
+        impl ::core::str::FromStr for $enum_set {
+            type Err = $crate::ParseListError;
+
+            fn from_str(s: &str) -> Result<Self, $crate::ParseListError> {
+                if s.trim().is_empty() {
+                    return Ok(Self::new());
+                }
+                s.split(',')
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let value = value.trim();
+                        $ty::try_from_str(value).ok_or_else(|| $crate::ParseListError { index, value: $crate::alloc::string::String::from(value) })
+                    })
+                    .collect()
+            }
+        }
+
+        impl From<$ty> for $enum_set {
+            fn from(variant: $ty) -> Self {
+                Self(Self::bit(&variant))
+            }
+        }
+
+        impl ::core::iter::FromIterator<$ty> for $enum_set {
+            fn from_iter<I: IntoIterator<Item = $ty>>(iter: I) -> Self {
+                let mut set = Self::new();
+                set.extend(iter);
+                set
+            }
+        }
+
+        impl Extend<$ty> for $enum_set {
+            fn extend<I: IntoIterator<Item = $ty>>(&mut self, iter: I) {
+                iter.into_iter().for_each(|variant| {
+                    self.insert(variant);
+                })
+            }
+        }
+
+        impl ::core::ops::BitOr for $enum_set {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitAnd for $enum_set {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl ::core::ops::Sub for $enum_set {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 & !rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitOrAssign for $enum_set {
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl ::core::ops::BitAndAssign for $enum_set {
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl ::core::ops::SubAssign for $enum_set {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+    (@as_str $rename_all:tt { $($variant:ident $(=> $val:literal)?,)* }) => {
+        pub const fn as_str(&self) -> &'static str {
+            match *self {
+                $(Self::$variant => $crate::str_enum_base!(@value $rename_all $variant $($val)?),)*
+            }
+        }
+    };
+    (@value $rename_all:tt $variant:ident $val:literal) => {
+        $val
+    };
+    (@value [] $variant:ident) => {
+        $crate::unraw(stringify!($variant))
+    };
+    (@value [$rename_all:tt] $variant:ident) => {{
+        const RULE: $crate::RenameRule = $crate::str_enum_base!(@rename_rule $rename_all);
+        const LEN: usize = RULE.renamed_len(stringify!($variant));
+        const BYTES: [u8; LEN] = RULE.rename(stringify!($variant));
+        const VALUE: &str = match str::from_utf8(&BYTES) {
+            Ok(o) => o,
+            Err(_) => panic!(),
+        };
+        VALUE
+    }};
+    (@rename_rule "lowercase") => { $crate::RenameRule::LowerCase };
+    (@rename_rule "UPPERCASE") => { $crate::RenameRule::UpperCase };
+    (@rename_rule "PascalCase") => { $crate::RenameRule::PascalCase };
+    (@rename_rule "camelCase") => { $crate::RenameRule::CamelCase };
+    (@rename_rule "snake_case") => { $crate::RenameRule::SnakeCase };
+    (@rename_rule "SCREAMING_SNAKE_CASE") => { $crate::RenameRule::ScreamingSnakeCase };
+    (@rename_rule "kebab-case") => { $crate::RenameRule::KebabCase };
+    (@rename_rule "SCREAMING-KEBAB-CASE") => { $crate::RenameRule::ScreamingKebabCase };
+    (@rename_rule $other:tt) => {
+        compile_error!(concat!(
+            "unknown rename_all rule ",
+            stringify!($other),
+            ", expected one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\" or \"SCREAMING-KEBAB-CASE\""
+        ))
+    };
+    (@documentation) => {
+        None
+    };
+    (@documentation $($doc:literal)+) => {
+        Some(concat!($($doc, "\n"),+).trim_ascii())
+    };
+    (@parse_opts_const $s:ident, [], $variants:tt) => {
+        $crate::str_enum_base!(@match_const $s, false, $variants)
+    };
+    (@parse_opts_const $s:ident, [case_insensitive $($rest:ident)*], $variants:tt) => {
+        match $crate::str_enum_base!(@parse_opts_const $s, [$($rest)*], $variants) {
+            Some(variant) => Some(variant),
+            None => $crate::str_enum_base!(@match_const $s, true, $variants),
+        }
+    };
+    (@parse_opts_const $s:ident, [trim $($rest:ident)*], $variants:tt) => {
+        match $crate::str_enum_base!(@parse_opts_const $s, [$($rest)*], $variants) {
+            Some(variant) => Some(variant),
+            None => {
+                let $s = $s.trim_ascii();
+                $crate::str_enum_base!(@parse_opts_const $s, [$($rest)*], $variants)
+            }
+        }
+    };
+    (@parse_opts_const $s:ident, [$opt:ident $($rest:ident)*], $variants:tt) => {
+        $crate::str_enum_base!(@parse_opts_const $s, [$($rest)*], $variants)
+    };
+    (@match_const $s:ident, $ignore_ascii_case:literal, { $($variant:ident $(($($($alias_marker:ident)* $other_valid:literal),*))?,)* }) => {
+        $(
+            if $crate::const_str_eq($s, Self::$variant.as_str(), $ignore_ascii_case) {
+                Some(Self::$variant)
+            }
+            $($(
+                else if $crate::str_enum_alias!(@scope $($alias_marker)*).allows($crate::AliasScope::Parse) && $crate::const_str_eq($s, $other_valid, $ignore_ascii_case) {
+                    Some(Self::$variant)
+                }
+            )*)?
+            else
+        )* {
+            None
+        }
+    };
+    (@description) => { "" };
+    (@description $description:literal) => { $description };
+    (@deprecated) => { false };
+    (@deprecated deprecated $($args:tt)*) => { true };
+    (@parse_skip) => { false };
+    (@parse_skip skip $($rest:ident)*) => { true };
+    (@parse_skip fallback $($rest:ident)*) => { $crate::str_enum_base!(@parse_skip $($rest)*) };
+    (@parse_skip $opt:ident $($rest:ident)*) => {
+        compile_error!(concat!("unknown variant parse option `", stringify!($opt), "`, expected `skip` or `fallback`"))
+    };
+    // the variants not marked `#[parse(skip)]`, built with from_index so the enum doesn't need to be Copy
+    (@clap_variants $ty:ident) => {
+        &[]
+    };
+    (@clap_variants $ty:ident $first:ident $($variant:ident)*) => {{
+        const VARIANTS: &[$ty] = &{
+            let mut buf = [const { $ty::$first }; $ty::NUM_EXPECTED_VALUES];
+            let mut idx = 0usize;
+            let mut buf_idx = 0usize;
+            while idx < $ty::NUM_VARIANTS {
+                let variant = $ty::from_index(idx).unwrap();
+                if !variant.parse_skipped() {
+                    buf[buf_idx] = variant;
+                    buf_idx += 1;
+                }
+                idx += 1
+            }
+            buf
+        };
+        VARIANTS
+    }};
+    (@parse_fallback) => { false };
+    (@parse_fallback fallback $($rest:ident)*) => { true };
+    (@parse_fallback $opt:ident $($rest:ident)*) => { $crate::str_enum_base!(@parse_fallback $($rest)*) };
+    (AsRef $self:ident, [$($other:ty),*]) => {
+        $(
+            impl AsRef<$other> for $self {
+                fn as_ref(&self) -> &$other {
+                    <str as AsRef<$other>>::as_ref(self.as_str())
+                }
+            }
+        )*
+    };
+    (From $self:ident, [$($other:ty),*]) => {
+        $(
+            impl From<$self> for $other {
+                fn from(val: $self) -> $other {
+                    From::from(val.as_str())
+                }
+            }
+        )*
+    };
+    (From 'a $self:ident, [$($other:ty),*]) => {
+        $(
+            impl<'a> From<$self> for $other {
+                fn from(val: $self) -> $other {
+                    From::from(val.as_str())
+                }
+            }
+        )*
+    };
+    (From &$self:ident, [$($other:ty),*]) => {
+        $(
+            impl From<&$self> for $other {
+                fn from(val: &$self) -> $other {
+                    From::from(val.as_str())
+                }
+            }
+        )*
+    };
+    (From 'a &$self:ident, [$($other:ty),*]) => {
+        $(
+            impl<'a> From<&$self> for $other {
+                fn from(val: &$self) -> $other {
+                    From::from(val.as_str())
+                }
+            }
+        )*
+    };
+    (FromIterator $self:ident, [$($other:ty),*]) => {
+        $(
+            impl ::core::iter::FromIterator<$self> for $other {
+                fn from_iter<T>(iter: T) -> $other
+                where
+                    T: IntoIterator<Item = $self>
+                {
+                    <$other as ::core::iter::FromIterator<&'static str>>::from_iter(iter.into_iter().map(|s| s.as_str()))
+                }
+            }
+        )*
+    };
+    (FromIterator 'a $self:ident, [$($other:ty),*]) => {
+        $(
+            impl<'a> ::core::iter::FromIterator<$self> for $other {
+                fn from_iter<T>(iter: T) -> $other
+                where
+                    T: IntoIterator<Item = $self>
+                {
+                    <$other as ::core::iter::FromIterator<&'static str>>::from_iter(iter.into_iter().map(|s| s.as_str()))
+                }
+            }
+        )*
+    };
+    (PartialEq $self:ident, [$($other:ty),*]) => {
+        $(
+            impl PartialEq<$self> for $other {
+                fn eq(&self, rhs: &$self) -> bool {
+                    self.eq(rhs.as_str())
+                }
+            }
+
+            impl PartialEq<$other> for $self {
+                fn eq(&self, rhs: &$other) -> bool {
+                    self.as_str().eq(rhs)
+                }
+            }
+        )*
+    };
+    (PartialEq 'a $self:ident, [$($other:ty),*]) => {
+        $(
+            impl<'a> PartialEq<$self> for $other {
+                fn eq(&self, rhs: &$self) -> bool {
+                    self.eq(rhs.as_str())
+                }
+            }
+
+            impl<'a> PartialEq<$other> for $self {
+                fn eq(&self, rhs: &$other) -> bool {
+                    self.as_str().eq(rhs)
+                }
+            }
+        )*
+    };
+    (PartialEq &$self:ident, [$($other:ty),*]) => {
+        $(
+            impl PartialEq<&$self> for $other {
+                fn eq(&self, rhs: &&$self) -> bool {
+                    self.eq(rhs.as_str())
+                }
+            }
+
+            impl PartialEq<$other> for &$self {
+                fn eq(&self, rhs: &$other) -> bool {
+                    self.as_str().eq(rhs)
+                }
+            }
+        )*
+    };
+    (PartialEq 'a &$self:ident, [$($other:ty),*]) => {
+        $(
+            impl<'a> PartialEq<&$self> for $other {
+                fn eq(&self, rhs: &&$self) -> bool {
+                    self.eq(rhs.as_str())
+                }
+            }
+
+            impl<'a> PartialEq<$other> for &$self {
+                fn eq(&self, rhs: &$other) -> bool {
+                    self.as_str().eq(rhs)
+                }
+            }
+        )*
+    };
+    (PartialOrd $self:ident, [$($other:ty),*]) => {
+        $(
+            impl PartialOrd<$self> for $other {
+                fn partial_cmp(&self, rhs: &$self) -> Option<::core::cmp::Ordering> {
+                    self.partial_cmp(rhs.as_str())
+                }
+            }
+        )*
+    };
+    (PartialOrd 'a $self:ident, [$($other:ty),*]) => {
+        $(
+            impl<'a> PartialOrd<$self> for $other {
+                fn partial_cmp(&self, rhs: &$self) -> Option<::core::cmp::Ordering> {
+                    self.partial_cmp(rhs.as_str())
+                }
+            }
+        )*
+    };
+    (FromStr $(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $(
+            $crate::str_enum_base!(@error_struct $vis $error_ty [] [] [$($error_opt $(($($error_opt_arg)*))?)*]);
+
+            impl $error_ty {
+                #[doc = "Self's error string before `{values}` is replaced with the enum's `EXPECTED_LIST`. You do not need this."]
+                const EXPECTED_STR_TEMPLATE: &str = $crate::str_enum_base!(@error_template $ty [$($error_opt $(($($error_opt_arg)*))?)*]);
+                #[doc = "Length of Self's error string. You do not need this."]
+                const EXPECTED_STR_LEN: usize = $crate::fill_template(Self::EXPECTED_STR_TEMPLATE, $ty::EXPECTED_LIST.as_bytes(), &mut []);
+                #[doc = "Bytes of Self's error string. You do not need this."]
+                const EXPECTED_STR_BYTES: [u8; Self::EXPECTED_STR_LEN] = {
+                    let mut buf = [0u8; Self::EXPECTED_STR_LEN];
+                    $crate::fill_template(Self::EXPECTED_STR_TEMPLATE, $ty::EXPECTED_LIST.as_bytes(), &mut buf);
+                    buf
+                };
+                #[doc = "&'static str of `Self::EXPECTED_STR_BYTES`. You do not need this."]
+                const EXPECTED_STR: &str = {
+                    match str::from_utf8(&Self::EXPECTED_STR_BYTES) {
+                        Ok(o) => o,
+                        Err(_) => panic!(),
+                    }
+                };
+            }
+
+            impl ::core::fmt::Display for $error_ty {
+                fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    <str as ::core::fmt::Display>::fmt(Self::EXPECTED_STR, fmt)?;
+                    let Some(input) = self.captured_input() else {
+                        return Ok(());
+                    };
+                    write!(fmt, ", found {input:?}")?;
+                    match $ty::suggest(input) {
+                        Some(suggestion) => write!(fmt, ", did you mean {suggestion:?}?"),
+                        None => Ok(()),
+                    }
+                }
+            }
+
+            impl ::core::error::Error for $error_ty {}
+
+            $crate::str_enum_miette!({
+                impl $crate::miette::Diagnostic for $error_ty {
+                    fn help<'a>(&'a self) -> Option<$crate::alloc::boxed::Box<dyn ::core::fmt::Display + 'a>> {
+                        Some($crate::alloc::boxed::Box::new($crate::alloc::format!("expected one of {}", $ty::EXPECTED_LIST)))
+                    }
+
+                    fn source_code(&self) -> Option<&dyn $crate::miette::SourceCode> {
+                        self.captured_source()
+                    }
+
+                    fn labels(&self) -> Option<$crate::alloc::boxed::Box<dyn Iterator<Item = $crate::miette::LabeledSpan> + '_>> {
+                        // the whole input is what failed to parse, so the label spans all of it
+                        let input = self.captured_input()?;
+                        let label = match $ty::suggest(input) {
+                            Some(suggestion) => $crate::alloc::format!("did you mean {suggestion:?}?"),
+                            None => $crate::alloc::string::String::from(concat!("not a valid ", stringify!($ty))),
+                        };
+                        Some($crate::alloc::boxed::Box::new(::core::iter::once($crate::miette::LabeledSpan::new_with_span(Some(label), 0..input.len()))))
+                    }
+                }
+            });
+
+            impl ::core::str::FromStr for $ty {
+                type Err = $error_ty;
+
+                fn from_str(s: &str) -> Result<$ty, Self::Err> {
+                    match Self::try_from_str(s) {
+                        Some(variant) => Ok(variant),
+                        None => Err($error_ty::from_input(s))
+                    }
+                }
+            }
+
+            impl TryFrom<&str> for $ty {
+                type Error = $error_ty;
+
+                fn try_from(s: &str) -> Result<$ty, Self::Error> {
+                    match Self::try_from_str(s) {
+                        Some(variant) => Ok(variant),
+                        None => Err($error_ty::from_input(s))
+                    }
+                }
+            }
+
+            impl TryFrom<$crate::alloc::string::String> for $ty {
+                type Error = $error_ty;
+
+                fn try_from(s: $crate::alloc::string::String) -> Result<$ty, Self::Error> {
+                    match Self::try_from_str(&s) {
+                        Some(variant) => Ok(variant),
+                        None => Err($error_ty::from_input(s))
+                    }
+                }
+            }
+
+            impl TryFrom<&[u8]> for $ty {
+                type Error = $crate::Utf8EnumError<$error_ty>;
+
+                fn try_from(bytes: &[u8]) -> Result<$ty, Self::Error> {
+                    ::core::str::from_utf8(bytes)
+                        .map_err($crate::Utf8EnumError::Utf8)
+                        .and_then(|s| $ty::try_from(s).map_err($crate::Utf8EnumError::InvalidVariant))
+                }
+            }
+
+            impl TryFrom<$crate::alloc::vec::Vec<u8>> for $ty {
+                type Error = $crate::Utf8EnumError<$error_ty>;
+
+                fn try_from(bytes: $crate::alloc::vec::Vec<u8>) -> Result<$ty, Self::Error> {
+                    $ty::try_from(bytes.as_slice())
+                }
+            }
+
+            impl<'a> TryFrom<$crate::alloc::borrow::Cow<'a, str>> for $ty {
+                type Error = $error_ty;
+
+                fn try_from(s: $crate::alloc::borrow::Cow<'a, str>) -> Result<$ty, Self::Error> {
+                    $ty::try_from(&*s)
+                }
+            }
+        )?
+    }
+}
+
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! str_enum_std {
+    ($ty:ident, [$($error_ty:ident)?], $skip_impls:tt) => {
+        $crate::str_enum_base!(@unless_skipped AsRef $skip_impls {
+            $crate::str_enum_base!(AsRef $ty, [$crate::std::ffi::OsStr, $crate::std::path::Path]);
+        });
+        // From<&Self> for both is already covered by their blanket From<&T: AsRef<OsStr>> impls
+        $crate::str_enum_base!(@unless_skipped From $skip_impls {
+            $crate::str_enum_base!(From $ty, [$crate::std::ffi::OsString, $crate::std::path::PathBuf]);
+        });
+
+        // PathBuf is already covered by its blanket FromIterator<P: AsRef<Path>> impl, which pushes each value as a segment
+        $crate::str_enum_base!(@unless_skipped FromIterator $skip_impls {
+            impl ::core::iter::FromIterator<$ty> for $crate::std::ffi::OsString {
+                fn from_iter<T>(iter: T) -> $crate::std::ffi::OsString
+                where
+                    T: IntoIterator<Item = $ty>
+                {
+                    iter.into_iter().map(|s| <str as AsRef<$crate::std::ffi::OsStr>>::as_ref(s.as_str())).collect()
+                }
+            }
+        });
+
+        $crate::str_enum_base!(@unless_skipped PartialEq $skip_impls {
+            $crate::str_enum_base!(PartialEq $ty, [$crate::std::ffi::OsStr, $crate::std::ffi::OsString, $crate::std::path::Path, $crate::std::path::PathBuf]);
+            $crate::str_enum_base!(PartialEq &$ty, [$crate::std::ffi::OsStr, $crate::std::ffi::OsString, $crate::std::path::Path, $crate::std::path::PathBuf]);
+        });
+        $crate::str_enum_base!(@unless_skipped PartialOrd $skip_impls {
+            $crate::str_enum_base!(PartialOrd $ty, [$crate::std::ffi::OsStr, $crate::std::ffi::OsString]);
+        });
+
+        $crate::str_enum_base!(@unless_skipped ToSocketAddrs $skip_impls {
+            impl $crate::std::net::ToSocketAddrs for $ty {
+                type Iter = $crate::alloc::vec::IntoIter<$crate::std::net::SocketAddr>;
+
+                fn to_socket_addrs(&self) -> $crate::std::io::Result<$crate::alloc::vec::IntoIter<$crate::std::net::SocketAddr>> {
+                    <str as $crate::std::net::ToSocketAddrs>::to_socket_addrs(self.as_str())
+                }
+            }
+        });
+
+        impl $ty {
+            #[doc = "Like `Self::try_from_str`, for an `OsStr` such as an environment variable or command line argument. Input that isn't valid UTF-8 gives `None`"]
+            pub fn try_from_os_str(s: &$crate::std::ffi::OsStr) -> Option<Self> {
+                s.to_str().and_then(Self::try_from_str)
+            }
+
+            #[doc = "Parse every line of `reader` with `Self::try_from_str`. Surrounding whitespace is trimmed and blank lines are skipped.\nErrors carry the 1-based line number they occurred on."]
+            pub fn parse_lines<R: $crate::std::io::BufRead>(reader: R) -> impl Iterator<Item = Result<Self, $crate::LineParseError>> {
+                reader.lines().enumerate().filter_map(|(idx, line)| {
+                    let line_number = idx + 1;
+                    match line {
+                        Ok(line) => {
+                            let value = line.trim();
+                            if value.is_empty() {
+                                return None;
+                            }
+                            Some(Self::try_from_str(value).ok_or_else(|| $crate::LineParseError::InvalidValue { line: line_number, value: $crate::alloc::string::String::from(value) }))
+                        }
+                        Err(error) => Some(Err($crate::LineParseError::Io { line: line_number, error })),
+                    }
+                })
+            }
+        }
+
+        $(
+            impl<'a> TryFrom<&'a $crate::std::ffi::OsStr> for $ty {
+                type Error = $crate::Utf8EnumError<$error_ty>;
+
+                fn try_from(value: &'a $crate::std::ffi::OsStr) -> Result<$ty, Self::Error> {
+                    <&'a str as TryFrom<&'a $crate::std::ffi::OsStr>>::try_from(value)
+                    .map_err($crate::Utf8EnumError::Utf8)
+                    .and_then(|s| $ty::try_from(s).map_err($crate::Utf8EnumError::InvalidVariant))
+                }
+            }
+
+            impl TryFrom<$crate::std::ffi::OsString> for $ty {
+                type Error = $crate::Utf8EnumError<$error_ty>;
+
+                fn try_from(value: $crate::std::ffi::OsString) -> Result<$ty, Self::Error> {
+                    $ty::try_from(value.as_os_str())
+                }
+            }
+
+            impl<'a> TryFrom<&'a $crate::std::path::Path> for $ty {
+                type Error = $crate::Utf8EnumError<$error_ty>;
+
+                fn try_from(value: &'a $crate::std::path::Path) -> Result<$ty, Self::Error> {
+                    $ty::try_from(value.as_os_str())
+                }
+            }
+
+            impl TryFrom<$crate::std::path::PathBuf> for $ty {
+                type Error = $crate::Utf8EnumError<$error_ty>;
+
+                fn try_from(value: $crate::std::path::PathBuf) -> Result<$ty, Self::Error> {
+                    $ty::try_from(value.as_os_str())
+                }
+            }
+        )?
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! str_enum_std {
+    ($ty:ident, [$($error_ty:ident)?], $skip_impls:tt) => {};
+}
+
+#[cfg(feature = "conversions")]
+#[macro_export]
+macro_rules! str_enum_conversions {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "conversions"))]
+#[macro_export]
+macro_rules! str_enum_conversions {
+    ($impls:tt) => {};
+}
+
+// also what strum's IntoStaticStr derive generates, so it comes with either feature
+#[cfg(any(feature = "conversions", feature = "strum"))]
+#[macro_export]
+macro_rules! str_enum_into_static_str {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(any(feature = "conversions", feature = "strum")))]
+#[macro_export]
+macro_rules! str_enum_into_static_str {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "schemars")]
+#[macro_export]
+macro_rules! str_enum_schemars {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[macro_export]
+macro_rules! str_enum_schemars {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "utoipa")]
+#[macro_export]
+macro_rules! str_enum_utoipa {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[macro_export]
+macro_rules! str_enum_utoipa {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "clap")]
+#[macro_export]
+macro_rules! str_enum_clap {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "clap"))]
+#[macro_export]
+macro_rules! str_enum_clap {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "rocket")]
+#[macro_export]
+macro_rules! str_enum_rocket {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "rocket"))]
+#[macro_export]
+macro_rules! str_enum_rocket {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "async-graphql")]
+#[macro_export]
+macro_rules! str_enum_async_graphql {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "async-graphql"))]
+#[macro_export]
+macro_rules! str_enum_async_graphql {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "http")]
+#[macro_export]
+macro_rules! str_enum_http {
+    (#[http] { $($impls:tt)* }) => {
+        $($impls)*
+    };
+    ($impls:tt) => {};
+}
+
+#[cfg(not(feature = "http"))]
+#[macro_export]
+macro_rules! str_enum_http {
+    ($(#[http])? $impls:tt) => {};
+}
+
+#[cfg(feature = "juniper")]
+#[macro_export]
+macro_rules! str_enum_juniper {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "juniper"))]
+#[macro_export]
+macro_rules! str_enum_juniper {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "sqlx")]
+#[macro_export]
+macro_rules! str_enum_sqlx {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "sqlx"))]
+#[macro_export]
+macro_rules! str_enum_sqlx {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "miette")]
+#[macro_export]
+macro_rules! str_enum_miette {
+    ({ $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "miette"))]
+#[macro_export]
+macro_rules! str_enum_miette {
+    ($impls:tt) => {};
+}
+
+#[cfg(feature = "extras")]
+#[macro_export]
+macro_rules! str_enum_extras {
+    ($group:ident { $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(not(feature = "extras"))]
+#[macro_export]
+macro_rules! str_enum_extras {
+    (Add $impls:tt) => {};
+    (Extend $impls:tt) => {};
+    (FromIterator $impls:tt) => {};
+    (Index $impls:tt) => {};
+    (ToSocketAddrs $impls:tt) => {};
+    ($group:ident { $($impls:tt)* }) => {
+        $($impls)*
+    };
+}
+
+#[cfg(feature = "strum")]
+#[macro_export]
+macro_rules! str_enum_strum {
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        impl $crate::strum::EnumCount for $ty {
+            const COUNT: usize = $ty::NUM_VARIANTS;
+        }
+
+        $(
+            impl $crate::strum::IntoDiscriminant for $ty {
+                type Discriminant = $repr;
+
+                fn discriminant(&self) -> Self::Discriminant {
+                    self.into_repr()
+                }
+            }
+        )?
+
+        impl $crate::strum::IntoEnumIterator for $ty {
+            type Iterator = $crate::Variants<$ty>;
+
+            fn iter() -> Self::Iterator {
+                $ty::iter()
+            }
+        }
+
+        impl $crate::strum::VariantArray for $ty {
+            const VARIANTS: &'static [Self] = Self::ALL_VARIANTS;
+        }
+
+        impl $crate::strum::VariantIterator for $ty {
+            type Iterator = $crate::Variants<$ty>;
+
+            fn iter() -> Self::Iterator {
+                $ty::iter()
+            }
+        }
+
+        impl $crate::strum::VariantNames for $ty {
+            const VARIANTS: &'static [&'static str] = $ty::VARIANT_NAMES;
+        }
+
+        #[allow(deprecated)]
+        impl $crate::strum::EnumMessage for $ty {
+            fn get_message(&self) -> Option<&'static str> {
+                Some(self.description()).filter(|description| !description.is_empty())
+            }
+
+            fn get_detailed_message(&self) -> Option<&'static str> {
+                self.get_message()
+            }
+
+            fn get_documentation(&self) -> Option<&'static str> {
+                self.documentation()
+            }
+
+            fn get_serializations(&self) -> &'static [&'static str] {
+                match *self {
+                    $($ty::$variant => {
+                        const SERIALIZATIONS: &[&str] = &[$ty::$variant.as_str(), $($($other_valid,)*)?];
+                        SERIALIZATIONS
+                    })*
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::strum::EnumProperty for $ty {
+            fn get_str(&self, prop: &str) -> Option<&'static str> {
+                $crate::str_enum_strum!(@property self, prop, as_str, { $($variant $({ $($prop_key: $prop_val),* })?,)* })
+            }
+
+            fn get_int(&self, prop: &str) -> Option<i64> {
+                $crate::str_enum_strum!(@property self, prop, as_int, { $($variant $({ $($prop_key: $prop_val),* })?,)* })
+            }
+
+            fn get_bool(&self, prop: &str) -> Option<bool> {
+                $crate::str_enum_strum!(@property self, prop, as_bool, { $($variant $({ $($prop_key: $prop_val),* })?,)* })
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $crate::strum::VariantMetadata for $ty {
+            const VARIANT_COUNT: usize = Self::ALL_VARIANTS.len();
+            const VARIANT_NAMES: &'static [&'static str] = &[$(stringify!($variant),)*];
+
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    $(Self::$variant => stringify!($variant),)*
+                }
+            }
+        }
+    };
+    (@property $self:ident, $prop:ident, $as:ident, { $($variant:ident $({ $($prop_key:ident: $prop_val:literal),* })?,)* }) => {
+        match *$self {
+            $(Self::$variant => {
+                $($(
+                    if $prop == stringify!($prop_key) {
+                        return $crate::PropertyValue::$as($prop_val);
+                    }
+                )*)?
+                None
+            })*
+        }
+    };
+}
+
+#[macro_export]
+#[cfg(not(feature = "strum"))]
+macro_rules! str_enum_strum {
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {};
+}
+
+#[cfg(all(feature = "verification", kani))]
+#[macro_export]
+macro_rules! str_enum_kani {
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        const _: () = {
+            #[kani::proof]
+            fn str_enum_values_round_trip() {
+                let idx: usize = kani::any();
+                kani::assume(idx < $ty::NUM_VARIANTS);
+                let variant = &$ty::ALL_VARIANTS[idx];
+                kani::assume(!variant.parse_skipped());
+                let parsed = $ty::try_from_str(variant.as_str());
+                assert!(parsed.is_some_and(|parsed| parsed.as_str() == variant.as_str()));
+            }
+
+            #[kani::proof]
+            fn str_enum_spellings_do_not_collide() {
+                // (spelling, canonical value of the variant it belongs to, scope it is accepted in)
+                const SPELLINGS: &[(&str, &str, $crate::AliasScope)] = &[
+                    $(
+                        ($ty::$variant.as_str(), $ty::$variant.as_str(), $crate::AliasScope::All),
+                        $($(($other_valid, $ty::$variant.as_str(), $crate::str_enum_alias!(@scope $($alias_marker)*)),)*)?
+                    )*
+                ];
+                let first: usize = kani::any();
+                let second: usize = kani::any();
+                kani::assume(first < SPELLINGS.len() && second < SPELLINGS.len());
+                let (first_spelling, first_owner, first_scope) = SPELLINGS[first];
+                let (second_spelling, second_owner, second_scope) = SPELLINGS[second];
+                if first_owner != second_owner && first_scope.allows(second_scope) {
+                    assert!(first_spelling != second_spelling);
+                }
+            }
+
+            $crate::str_enum_kani!(@repr $ty, [$($repr)?], { $($variant,)* });
+        };
+    };
+    (@repr $ty:ident, [], $variants:tt) => {};
+    (@repr $ty:ident, [$repr:ty], { $($variant:ident,)* }) => {
+        #[kani::proof]
+        fn str_enum_discriminants_round_trip() {
+            const DISCRIMINANTS: &[$repr] = &[$($ty::$variant as $repr,)*];
+            let idx: usize = kani::any();
+            kani::assume(idx < DISCRIMINANTS.len());
+            let discriminant = DISCRIMINANTS[idx];
+            assert_eq!(DISCRIMINANTS.iter().position(|other| *other == discriminant), Some(idx));
+        }
+    };
+}
+
+#[cfg(not(all(feature = "verification", kani)))]
+#[macro_export]
+macro_rules! str_enum_kani {
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {};
+}
+
+#[macro_export]
+#[cfg(feature = "serde")]
+macro_rules! str_enum_serde {
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        #[allow(deprecated)]
+        impl $ty {
+            #[doc = "What Self's serde deserialize error says was expected. You do not need this."]
+            const SERDE_EXPECTED_STR: &str = {
+                match str::from_utf8(&Self::SERDE_EXPECTED_STR_BYTES) {
+                    Ok(o) => o,
+                    Err(_) => panic!(),
+                }
+            };
+
+            #[doc = "Bytes of `Self::SERDE_EXPECTED_STR`. You do not need this."]
+            const SERDE_EXPECTED_STR_BYTES: [u8; "one of ".len() + Self::EXPECTED_LIST.len()] = {
+                let mut buf = [0u8; "one of ".len() + Self::EXPECTED_LIST.len()];
+                let idx = Self::push_bytes(&mut buf, 0, b"one of ");
+                Self::push_bytes(&mut buf, idx, Self::EXPECTED_LIST.as_bytes());
+                buf
+            };
+
+            #[doc = "Whether `#[serde(compact)]` or `#[serde(compact_index)]` was given, making non human-readable formats use a number. You do not need this."]
+            const SERDE_COMPACT: bool = $crate::str_enum_serde!(@compact $($($serde_opt)*)?);
+
+            #[doc = "Whether `#[serde(compact_index)]` was given, making that number the index in declaration order rather than the discriminant. You do not need this."]
+            const SERDE_COMPACT_INDEX: bool = $crate::str_enum_serde!(@compact_index $($($serde_opt)*)?);
+
+            #[doc = "Whether `#[serde(integers)]` was given, making deserializing also accept the discriminant. You do not need this."]
+            const SERDE_INTEGERS: bool = $crate::str_enum_serde!(@integers $($($serde_opt)*)?);
+
+            #[doc = "The variant with this discriminant, leaving out `#[parse(skip)]` variants. You do not need this."]
+            fn from_serde_discriminant(discriminant: $crate::str_enum_serde!(@compact_repr $($repr)?)) -> Option<Self> {
+                type Compact = $crate::str_enum_serde!(@compact_repr $($repr)?);
+                $(
+                    if discriminant == Self::$variant as Compact && !Self::$variant.parse_skipped() {
+                        return Some(Self::$variant);
+                    }
+                )*
+                None
+            }
+
+            #[doc = "The visitor `Deserialize` uses for strings and bytes, for delegating to from your own `Deserialize` impls. Accepts the same forms as deserializing `Self` does."]
+            pub fn serde_visitor<'de>() -> impl $crate::serde::de::Visitor<'de, Value = Self> {
+                struct StrEnumVisitor;
+
+                impl<'de> $crate::serde::de::Visitor<'de> for StrEnumVisitor {
+                    type Value = $ty;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        <str as ::core::fmt::Display>::fmt($ty::SERDE_EXPECTED_STR, formatter)
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<$ty, E>
+                    where
+                        E: $crate::serde::de::Error,
+                    {
+                        $ty::try_from_str_scoped(v, $crate::AliasScope::Serde).ok_or_else(|| E::invalid_value($crate::serde::de::Unexpected::Str(v), &$ty::SERDE_EXPECTED_STR))
+                    }
+
+                    fn visit_bytes<E>(self, v: &[u8]) -> Result<$ty, E>
+                    where
+                        E: $crate::serde::de::Error,
+                    {
+                        match str::from_utf8(v) {
+                            Ok(s) => self.visit_str(s),
+                            Err(_) => Err(E::invalid_value($crate::serde::de::Unexpected::Bytes(v), &$ty::SERDE_EXPECTED_STR)),
+                        }
+                    }
+
+                    fn visit_u64<E>(self, v: u64) -> Result<$ty, E>
+                    where
+                        E: $crate::serde::de::Error,
+                    {
+                        if !$ty::SERDE_INTEGERS {
+                            return Err(E::invalid_type($crate::serde::de::Unexpected::Unsigned(v), &self));
+                        }
+                        v.try_into().ok().and_then($ty::from_serde_discriminant).ok_or_else(|| E::invalid_value($crate::serde::de::Unexpected::Unsigned(v), &"a valid discriminant"))
+                    }
+
+                    fn visit_i64<E>(self, v: i64) -> Result<$ty, E>
+                    where
+                        E: $crate::serde::de::Error,
+                    {
+                        if !$ty::SERDE_INTEGERS {
+                            return Err(E::invalid_type($crate::serde::de::Unexpected::Signed(v), &self));
+                        }
+                        v.try_into().ok().and_then($ty::from_serde_discriminant).ok_or_else(|| E::invalid_value($crate::serde::de::Unexpected::Signed(v), &"a valid discriminant"))
+                    }
+                }
+
+                StrEnumVisitor
+            }
+        }
+
+
+        $(
+            impl $crate::serde::de::Expected for $error_ty {
+                fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    <str as ::core::fmt::Display>::fmt($ty::SERDE_EXPECTED_STR, formatter)
+                }
+            }
+
+            impl $crate::serde::Serialize for $error_ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::serde::Serializer,
+                {
+                    use $crate::serde::ser::SerializeStruct;
+
+                    let found = self.captured_input();
+                    let mut state = serializer.serialize_struct(stringify!($error_ty), 1 + usize::from(found.is_some()))?;
+                    state.serialize_field("expected", &$ty::EXPECTED_VALUES)?;
+                    if let Some(found) = found {
+                        state.serialize_field("found", found)?;
+                    }
+                    state.end()
+                }
+            }
+        )?
+
+        #[allow(deprecated)]
+        impl $crate::serde::Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                if Self::SERDE_COMPACT_INDEX && !serializer.is_human_readable() {
+                    (self.index() as u32).serialize(serializer)
+                } else if Self::SERDE_COMPACT && !serializer.is_human_readable() {
+                    type Compact = $crate::str_enum_serde!(@compact_repr $($repr)?);
+                    match *self {
+                        $(Self::$variant => (Self::$variant as Compact).serialize(serializer),)*
+                    }
+                } else {
+                    self.as_str().serialize(serializer)
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'de> $crate::serde::Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::serde::Deserializer<'de>,
+            {
+                if $ty::SERDE_COMPACT_INDEX && !deserializer.is_human_readable() {
+                    let index = <u32 as $crate::serde::Deserialize>::deserialize(deserializer)?;
+                    return $ty::from_index(index as usize).filter(|variant| !variant.parse_skipped()).ok_or_else(|| <D::Error as $crate::serde::de::Error>::invalid_value($crate::serde::de::Unexpected::Unsigned(index.into()), &"a valid variant index"));
+                }
+
+                if $ty::SERDE_COMPACT && !deserializer.is_human_readable() {
+                    type Compact = $crate::str_enum_serde!(@compact_repr $($repr)?);
+                    let discriminant = <Compact as $crate::serde::Deserialize>::deserialize(deserializer)?;
+                    return $ty::from_serde_discriminant(discriminant).ok_or_else(|| <D::Error as $crate::serde::de::Error>::invalid_value($crate::serde::de::Unexpected::Other("unknown discriminant"), &"a valid discriminant"));
+                }
+
+                if $ty::SERDE_INTEGERS {
+                    // self-describing formats only, the input says whether it is a string or a number
+                    return deserializer.deserialize_any(Self::serde_visitor());
+                }
+
+                deserializer.deserialize_str(Self::serde_visitor())
+            }
+        }
+
+        // the value is 'static, so it can be borrowed for any 'de
+        impl<'de, E> $crate::serde::de::IntoDeserializer<'de, E> for $ty
+        where
+            E: $crate::serde::de::Error,
+        {
+            type Deserializer = $crate::serde::de::value::BorrowedStrDeserializer<'de, E>;
+
+            fn into_deserializer(self) -> Self::Deserializer {
+                $crate::serde::de::value::BorrowedStrDeserializer::new(self.as_str())
+            }
+        }
+
+        $($crate::str_enum_serde!(@with $vis $ty $serde_with);)?
+        $($crate::str_enum_serde!(@catch_all $ty $catch_all);)?
+        $($crate::str_enum_serde!(@enum_map $ty $enum_map);)?
+        $($crate::str_enum_serde!(@enum_set $ty $enum_set);)?
+    };
+    (@catch_all $ty:ident $catch_all:ident) => {
+        impl $crate::serde::Serialize for $catch_all {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> $crate::serde::Deserialize<'de> for $catch_all {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_str($crate::StrVisitor::new("a string", |s| {
+                    Ok(match $ty::try_from_str_scoped(s, $crate::AliasScope::Serde) {
+                        Some(known) => $catch_all::Known(known),
+                        None => $catch_all::Other(s.into()),
+                    })
+                }))
+            }
+        }
+    };
+    // a map keyed by the variants, variants missing from the input are left as `T::default()`
+    (@enum_map $ty:ident $enum_map:ident) => {
+        impl<T: $crate::serde::Serialize> $crate::serde::Serialize for $enum_map<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                use $crate::serde::ser::SerializeMap;
+
+                let mut map = serializer.serialize_map(Some($ty::NUM_VARIANTS))?;
+                for (variant, value) in self.iter() {
+                    map.serialize_entry(&variant, value)?;
+                }
+                map.end()
+            }
+        }
+
+        impl<'de, T: $crate::serde::Deserialize<'de> + Default> $crate::serde::Deserialize<'de> for $enum_map<T> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::serde::Deserializer<'de>,
+            {
+                struct Visitor<T>(::core::marker::PhantomData<T>);
+
+                impl<'de, T: $crate::serde::Deserialize<'de> + Default> $crate::serde::de::Visitor<'de> for Visitor<T> {
+                    type Value = $enum_map<T>;
+
+                    fn expecting(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        fmt.write_str(concat!("a map with values of ", stringify!($ty), " as keys"))
+                    }
+
+                    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: $crate::serde::de::MapAccess<'de>,
+                    {
+                        let mut seen = [false; $ty::NUM_VARIANTS];
+                        let mut map = $enum_map::<T>::default();
+                        while let Some(variant) = access.next_key::<$ty>()? {
+                            let slot = variant.index();
+                            if seen[slot] {
+                                return Err(<A::Error as $crate::serde::de::Error>::custom(format_args!("duplicate key {:?}", variant.as_str())));
+                            }
+                            seen[slot] = true;
+                            map.0[slot] = access.next_value()?;
+                        }
+                        Ok(map)
+                    }
+                }
+
+                deserializer.deserialize_map(Visitor(::core::marker::PhantomData))
+            }
+        }
+    };
+    // a sequence of the variants in the set
+    (@enum_set $ty:ident $enum_set:ident) => {
+        impl $crate::serde::Serialize for $enum_set {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
-                S: $crate::serde::Serializer,
+                S: $crate::serde::Serializer,
+            {
+                serializer.collect_seq(self.iter())
+            }
+        }
+
+        impl<'de> $crate::serde::Deserialize<'de> for $enum_set {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::serde::Deserializer<'de>,
+            {
+                struct Visitor;
+
+                impl<'de> $crate::serde::de::Visitor<'de> for Visitor {
+                    type Value = $enum_set;
+
+                    fn expecting(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        fmt.write_str(concat!("a sequence of ", stringify!($ty), " values"))
+                    }
+
+                    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: $crate::serde::de::SeqAccess<'de>,
+                    {
+                        let mut set = $enum_set::new();
+                        while let Some(variant) = access.next_element::<$ty>()? {
+                            set.insert(variant);
+                        }
+                        Ok(set)
+                    }
+                }
+
+                deserializer.deserialize_seq(Visitor)
+            }
+        }
+    };
+    (@with $vis:vis $ty:ident $serde_with:ident) => {
+        #[doc = concat!("Helpers for fields holding [`", stringify!($ty), "`] values, for use with `#[serde(with = \"...\")]`")]
+        $vis mod $serde_with {
+            #[doc = "An `Option` where the empty string deserializes as `None`, and `None` serializes as the empty string"]
+            pub mod option_empty_as_none {
+                use super::super::$ty;
+
+                pub fn serialize<S>(value: &Option<$ty>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::serde::Serializer,
+                {
+                    serializer.serialize_str(value.as_ref().map_or("", $ty::as_str))
+                }
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<$ty>, D::Error>
+                where
+                    D: $crate::serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str($crate::StrVisitor::new($ty::SERDE_EXPECTED_STR, |s| {
+                        if s.is_empty() {
+                            return Ok(None);
+                        }
+                        $ty::try_from_str_scoped(s, $crate::AliasScope::Serde).map(Some).ok_or(s)
+                    }))
+                }
+            }
+
+            #[doc = "An `Option` where any string that isn't a valid value deserializes as `None`, and `None` serializes as the empty string"]
+            pub mod option_lenient {
+                use super::super::$ty;
+
+                pub fn serialize<S>(value: &Option<$ty>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::serde::Serializer,
+                {
+                    super::option_empty_as_none::serialize(value, serializer)
+                }
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<$ty>, D::Error>
+                where
+                    D: $crate::serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str($crate::StrVisitor::new("a string", |s| Ok($ty::try_from_str_scoped(s, $crate::AliasScope::Serde))))
+                }
+            }
+
+            #[doc = "A `Vec` stored as a single comma separated string, whitespace around each value is ignored"]
+            pub mod comma_separated {
+                use super::super::$ty;
+
+                pub fn serialize<S>(values: &[$ty], serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::serde::Serializer,
+                {
+                    let mut joined = $crate::alloc::string::String::new();
+                    for (idx, value) in values.iter().enumerate() {
+                        if idx != 0 {
+                            joined.push(',');
+                        }
+                        joined.push_str(value.as_str());
+                    }
+                    serializer.serialize_str(&joined)
+                }
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<$crate::alloc::vec::Vec<$ty>, D::Error>
+                where
+                    D: $crate::serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str($crate::StrVisitor::new($ty::SERDE_EXPECTED_STR, |s| {
+                        if s.trim().is_empty() {
+                            return Ok($crate::alloc::vec::Vec::new());
+                        }
+                        s.split(',')
+                            .map(|part| {
+                                let part = part.trim();
+                                $ty::try_from_str_scoped(part, $crate::AliasScope::Serde).ok_or(part)
+                            })
+                            .collect()
+                    }))
+                }
+            }
+
+            #[doc = "Like `comma_separated`, but values that aren't valid are skipped instead of erroring"]
+            pub mod comma_separated_lenient {
+                use super::super::$ty;
+
+                pub fn serialize<S>(values: &[$ty], serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::serde::Serializer,
+                {
+                    super::comma_separated::serialize(values, serializer)
+                }
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<$crate::alloc::vec::Vec<$ty>, D::Error>
+                where
+                    D: $crate::serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str($crate::StrVisitor::new("a string", |s| {
+                        Ok(s.split(',')
+                            .filter_map(|part| $ty::try_from_str_scoped(part.trim(), $crate::AliasScope::Serde))
+                            .collect())
+                    }))
+                }
+            }
+        }
+    };
+    (@compact) => { false };
+    (@compact compact $($rest:ident)*) => { true };
+    (@compact compact_index $($rest:ident)*) => { true };
+    (@compact integers $($rest:ident)*) => { $crate::str_enum_serde!(@compact $($rest)*) };
+    (@compact $opt:ident $($rest:ident)*) => {
+        compile_error!(concat!("unknown serde option `", stringify!($opt), "`, expected `compact`, `compact_index` or `integers`"))
+    };
+    (@compact_index) => { false };
+    (@compact_index compact_index $($rest:ident)*) => { true };
+    (@compact_index $opt:ident $($rest:ident)*) => { $crate::str_enum_serde!(@compact_index $($rest)*) };
+    (@integers) => { false };
+    (@integers integers $($rest:ident)*) => { true };
+    (@integers $opt:ident $($rest:ident)*) => { $crate::str_enum_serde!(@integers $($rest)*) };
+    (@compact_repr $repr:ty) => { $repr };
+    // without a repr the discriminants are `isize`, sent as `i64` so the format is the same on every platform
+    (@compact_repr) => { i64 };
+}
+
+#[macro_export]
+#[cfg(not(feature = "serde"))]
+macro_rules! str_enum_serde {
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {};
+}
+
+#[macro_export]
+#[cfg(feature = "lasso")]
+macro_rules! str_enum_lasso {
+    ($(#[doc = $doc:literal])* $(#[error_type $error_type:tt])? $(#[derive $derive:tt])? $(#[repr $repr:tt])? $(#[serde $serde:tt])? #[lasso $lasso:tt] $(#[$attr:ident $($attr_args:tt)*])* $vis:vis enum $ty:ident {}) => {
+        compile_error!("`#[lasso]` needs at least one variant");
+    };
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? #[lasso($lasso_keys:ident)] $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        #[doc = "Keys of every variant's value in a `lasso::Rodeo`, see `seed_interner`"]
+        #[allow(non_snake_case)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $lasso_keys<K = $crate::lasso::Spur> {
+            $(pub $variant: K,)*
+        }
+
+        #[allow(deprecated)]
+        impl<K: $crate::lasso::Key> $lasso_keys<K> {
+            #[doc = "The interned key for `variant`"]
+            pub fn get(&self, variant: &$ty) -> K {
+                match variant {
+                    $($ty::$variant => self.$variant,)*
+                }
+            }
+
+            #[doc = "The variant whose value was interned as `key`, if any"]
+            pub fn variant(&self, key: K) -> Option<$ty> {
+                $(
+                    if key == self.$variant {
+                        return Some($ty::$variant);
+                    }
+                )*
+                None
+            }
+        }
+
+        #[allow(deprecated)]
+        impl $ty {
+            #[doc = "Interns all values of `Self` into `rodeo` and returns the key of each variant"]
+            pub fn seed_interner<K, S>(rodeo: &mut $crate::lasso::Rodeo<K, S>) -> $lasso_keys<K>
+            where
+                K: $crate::lasso::Key,
+                S: ::core::hash::BuildHasher,
             {
-                self.as_str().serialize(serializer)
+                $lasso_keys {
+                    $($variant: rodeo.get_or_intern_static($ty::$variant.as_str()),)*
+                }
+            }
+        }
+    };
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {};
+}
+
+#[macro_export]
+#[cfg(not(feature = "lasso"))]
+macro_rules! str_enum_lasso {
+    ($(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {};
+}
+
+#[macro_export]
+macro_rules! str_enum {
+    (@include $base:ident [$($head:tt)*] enum $ty:ident { $($body:tt)* }) => {
+        $base!(@str_enum_include [$($head)*] $ty { $($body)* });
+    };
+    (@include $base:ident [$($head:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::str_enum!(@include $base [$($head)* $next] $($rest)*);
+    };
+    // drops the variants whose `#[cfg(...)]` is false before anything else sees them, input without any is passed on as is
+    (@cfg [$mode:ident] [$($head:tt)*] enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $crate::str_enum!(@attrs [$mode] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] $($head)* enum $ty { $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)* });
+    };
+    (@cfg [$mode:ident] [$($head:tt)*] enum $ty:ident { $($body:tt)* }) => {
+        $crate::str_enum!(@cfg_variants [$mode] [$($head)*] $ty [] $($body)*);
+    };
+    (@cfg [$mode:ident] [$($head:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::str_enum!(@cfg [$mode] [$($head)* $next] $($rest)*);
+    };
+    (@cfg [$mode:ident] [$($head:tt)*]) => {
+        $crate::str_enum!(@attrs [$mode] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] $($head)*);
+    };
+    (@cfg_variants [$mode:ident] [$($head:tt)*] $ty:ident [$($kept:tt)*] $(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)? $(, $($rest:tt)*)?) => {
+        $crate::str_enum!(@cfg_variants [$mode] [$($head)*] $ty [$($kept)* $(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,] $($($rest)*)?);
+    };
+    (@cfg_variants [$mode:ident] [$($head:tt)*] $ty:ident [$($kept:tt)*] $(#[doc = $variant_doc:literal])* $(#[cfg($($variant_cfg:tt)*)] $(#[doc = $more_variant_doc:literal])*)+ $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)? $(, $($rest:tt)*)?) => {
+        #[cfg(all($($($variant_cfg)*),+))]
+        $crate::str_enum!(@cfg_variants [$mode] [$($head)*] $ty [$($kept)* $(#[doc = $variant_doc])* $($(#[doc = $more_variant_doc])*)+ $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,] $($($rest)*)?);
+        #[cfg(not(all($($($variant_cfg)*),+)))]
+        $crate::str_enum!(@cfg_variants [$mode] [$($head)*] $ty [$($kept)*] $($($rest)*)?);
+    };
+    (@cfg_variants [$mode:ident] [$($head:tt)*] $ty:ident [$($kept:tt)*] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$mode] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] [] $($head)* enum $ty { $($kept)* $($rest)* });
+    };
+    // sorts the attributes into the order the other macros expect, keeping the ones str_enum doesn't use for the enum declaration
+    (@attrs [$($mode:tt)*] [$($phf:tt)+] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[phf] $($rest:tt)*) => {
+        compile_error!("duplicate `#[phf]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[phf] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)* #[phf]] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)+] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[include $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[include]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[include $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)* #[include $args]] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[doc = $doc_line:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)* #[doc = $doc_line]] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)+] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[error_type $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[error_type]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[error_type $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)* #[error_type $args]] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[derive($($trait:path),* $(,)?)] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)* $($trait,)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    // `#[repr(u8, align(4))]` is split up, layout reprs only go on the declaration and the integer one is the discriminant type
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[repr($first:ident $(($($first_args:tt)*))?, $($others:tt)+)] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] #[repr($first $(($($first_args)*))?)] #[repr($($others)+)] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[repr($only:ident $(($($only_args:tt)*))?,)] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] #[repr($only $(($($only_args)*))?)] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[repr(C)] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)* #[repr(C)]] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[repr(transparent)] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)* #[repr(transparent)]] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[repr(align $args:tt)] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)* #[repr(align $args)]] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)+] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[repr $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[repr]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[repr $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)* #[repr $args]] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)+] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[serde $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[serde]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[serde $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)* #[serde $args]] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)+] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[lasso $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[lasso]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[lasso $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)* #[lasso $args]] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)+] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[parse $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[parse]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[parse $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)* #[parse $args]] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)+] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[str_type $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[str_type]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[str_type $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)* #[str_type $args]] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)+] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[serde_with $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[serde_with]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[serde_with $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)* #[serde_with $args]] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)+] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[rename_all = $rule:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[rename_all]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[rename_all = $rule:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)* #[rename_all = $rule]] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)+] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[catch_all $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[catch_all]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[catch_all $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)* #[catch_all $args]] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)+] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[skip_impls $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[skip_impls]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[skip_impls $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)* #[skip_impls $args]] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)+] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[cmp $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[cmp]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[cmp $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)* #[cmp $args]] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)+] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[enum_map $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[enum_map]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[enum_map $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)* #[enum_map $args]] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)+] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[enum_set $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[enum_set]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[enum_set $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)* #[enum_set $args]] [$($constant)*] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)+] [$($http:tt)*] [$($attrs:tt)*] #[constant $args:tt] $($rest:tt)*) => {
+        compile_error!("duplicate `#[constant]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[constant $args:tt] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)* #[constant $args]] [$($http)*] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)+] [$($attrs:tt)*] #[http] $($rest:tt)*) => {
+        compile_error!("duplicate `#[http]` attribute");
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [] [$($attrs:tt)*] #[http] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [#[http]] [$($attrs)*] $($rest)*);
+    };
+    (@attrs [$($mode:tt)*] [$($phf:tt)*] [$($include:tt)*] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:tt)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] #[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::str_enum!(@attrs [$($mode)*] [$($phf)*] [$($include)*] [$($doc)*] [$($error_type)*] [$($derive)*] [$($repr)*] [$($serde)*] [$($lasso)*] [$($parse)*] [$($str_type)*] [$($serde_with)*] [$($rename_all)*] [$($catch_all)*] [$($skip_impls)*] [$($cmp)*] [$($enum_map)*] [$($enum_set)*] [$($constant)*] [$($http)*] [$($attrs)* #[$($attr)*]] $($rest)*);
+    };
+    (@attrs [define] [$($phf:tt)*] [#[include($base:ident)]] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:path,)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] $($rest:tt)*) => {
+        $crate::str_enum!(@include $base [$($phf)* $($doc)* $($error_type)* #[derive($($derive),*)] $($repr)* $($serde)* $($lasso)* $($parse)* $($str_type)* $($serde_with)* $($rename_all)* $($catch_all)* $($skip_impls)* $($cmp)* $($enum_map)* $($enum_set)* $($constant)* $($http)* $($attrs)*] $($rest)*);
+    };
+    (@attrs [impls] [$($phf:tt)*] [$($include:tt)+] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:path,)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] $($rest:tt)*) => {
+        compile_error!("`#[include]` only works with `str_enum!`, which declares the enum");
+    };
+    (@attrs [define] [$($phf:tt)*] [] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:path,)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] $($rest:tt)*) => {
+        $crate::str_enum_base!(@define $($doc)* $($error_type)* #[derive($($derive),*)] $($repr)* $($serde)* $($lasso)* $($parse)* $($str_type)* $($serde_with)* $($rename_all)* $($catch_all)* $($skip_impls)* $($cmp)* $($enum_map)* $($enum_set)* $($constant)* $($http)* #[attrs[$($attrs)*]] $($rest)*);
+        $crate::str_enum_impls!(@sorted $($phf)* $($doc)* $($error_type)* #[derive($($derive),*)] $($repr)* $($serde)* $($lasso)* $($parse)* $($str_type)* $($serde_with)* $($rename_all)* $($catch_all)* $($skip_impls)* $($cmp)* $($enum_map)* $($enum_set)* $($constant)* $($http)* $($rest)*);
+    };
+    (@attrs [impls] [$($phf:tt)*] [] [$($doc:tt)*] [$($error_type:tt)*] [$($derive:path,)*] [$($repr:tt)*] [$($serde:tt)*] [$($lasso:tt)*] [$($parse:tt)*] [$($str_type:tt)*] [$($serde_with:tt)*] [$($rename_all:tt)*] [$($catch_all:tt)*] [$($skip_impls:tt)*] [$($cmp:tt)*] [$($enum_map:tt)*] [$($enum_set:tt)*] [$($constant:tt)*] [$($http:tt)*] [$($attrs:tt)*] $($rest:tt)*) => {
+        $crate::str_enum_impls!(@sorted $($phf)* $($doc)* $($error_type)* #[derive($($derive),*)] $($repr)* $($serde)* $($lasso)* $($parse)* $($str_type)* $($serde_with)* $($rename_all)* $($catch_all)* $($skip_impls)* $($cmp)* $($enum_map)* $($enum_set)* $($constant)* $($http)* $($rest)*);
+    };
+    ($($rest:tt)*) => {
+        $crate::str_enum!(@cfg [define] [] $($rest)*);
+    };
+}
+
+/// Like [`str_enum!`], but for an enum that is already declared elsewhere: generates every impl without declaring the enum.
+/// Takes the same input, listing the existing variants with their values; `#[derive]`, `#[include]` and other attributes for the declaration are not used.
+/// ```
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Existing {
+///     First,
+///     Second,
+/// }
+///
+/// str_enum::str_enum_impls! {
+///     #[error_type(ExistingError)]
+///     enum Existing {
+///         First => "first",
+///         Second => "second"("2"),
+///     }
+/// }
+///
+/// assert_eq!("2".parse::<Existing>().unwrap(), Existing::Second);
+/// ```
+#[macro_export]
+macro_rules! str_enum_impls {
+    ($(@sorted)? $(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $crate::str_enum_base!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_strum!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_serde!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_lasso!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_kani!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_try_from_str!{
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        }
+
+        $crate::str_enum_base!(FromStr
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+    };
+    ($(@sorted)? #[phf] $(#[doc = $doc:literal])* $(#[error_type($error_ty:ident $(, $error_opt:ident $(($($error_opt_arg:tt)*))?)* $(,)?)])? $(#[derive($($derive_trait:path),* $(,)?)])? $(#[repr($repr:ty)])? $(#[serde($($serde_opt:ident),* $(,)?)])? $(#[lasso($lasso_keys:ident)])? $(#[parse($($parse_opt:ident),* $(,)?)])? $(#[str_type($str_type:ident)])? $(#[serde_with($serde_with:ident)])? $(#[rename_all = $rename_all:tt])? $(#[catch_all($catch_all:ident)])? $(#[skip_impls($($skip_impl:ident),* $(,)?)])? $(#[cmp($cmp:ident)])? $(#[enum_map($enum_map:ident)])? $(#[enum_set($enum_set:ident)])? $(#[constant($const_fn:ident: $const_ty:ty $(, $const_from_fn:ident)? $(,)?)])? $(#[http $($http:tt)*])? $vis:vis enum $ty:ident { $($(#[doc = $variant_doc:literal])* $(#[parse($($variant_parse_opt:ident),* $(,)?)])? $(#[deprecated $($variant_deprecated:tt)*])? $(#[predicate($variant_predicate:ident)])? $(#[default] $variant_default:vis)? $variant:ident $(= $variant_repr:literal)? $(=> $val:literal)? $(: $variant_const:literal)? $(($($($alias_marker:ident)* $other_valid:literal),* $(,)?))? $({ $($prop_key:ident: $prop_val:literal),* $(,)? })? $(? $variant_description:literal)?),* $(,)? }) => {
+        $crate::str_enum_base!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_strum!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_serde!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_lasso!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_kani!(
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+
+        $crate::str_enum_try_from_str!{
+            #[phf]
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        }
+
+        $crate::str_enum_base!(FromStr
+            $(#[doc = $doc])*
+            $(#[error_type($error_ty $(, $error_opt $(($($error_opt_arg)*))?)*)])?
+            $(#[derive($($derive_trait,)*)])?
+            $(#[repr($repr)])?
+            $(#[serde($($serde_opt),*)])?
+            $(#[lasso($lasso_keys)])?
+            $(#[parse($($parse_opt),*)])?
+            $(#[str_type($str_type)])?
+            $(#[serde_with($serde_with)])?
+            $(#[rename_all = $rename_all])?
+            $(#[catch_all($catch_all)])? $(#[skip_impls($($skip_impl),*)])? $(#[cmp($cmp)])? $(#[enum_map($enum_map)])? $(#[enum_set($enum_set)])? $(#[constant($const_fn: $const_ty $(, $const_from_fn)?)])? $(#[http $($http)*])?
+            $vis enum $ty {
+                $($(#[doc = $variant_doc])* $(#[parse($($variant_parse_opt),*)])? $(#[deprecated $($variant_deprecated)*])? $(#[predicate($variant_predicate)])? $(#[default] $variant_default)? $variant $(= $variant_repr)? $(=> $val)? $(: $variant_const)? $(($($($alias_marker)* $other_valid),*))? $({ $($prop_key: $prop_val),* })? $(? $variant_description)?,)*
+            }
+        );
+    };
+    (@sorted $($rest:tt)*) => {
+        compile_error!("expected `enum Name { Variant => \"value\", ... }` after the attributes");
+    };
+    ($($rest:tt)*) => {
+        $crate::str_enum!(@cfg [impls] [] $($rest)*);
+    };
+}
+
+/// The variant of a str_enum with the given value, checked at compile time.
+/// Only values match, not alternate forms, and an unknown value fails to compile.
+/// ```
+/// str_enum::str_enum! {
+///     #[derive(Debug, Clone, PartialEq)]
+///     enum Method {
+///         Get => "GET",
+///         Post => "POST",
+///     }
+/// }
+///
+/// assert_eq!(str_enum::variant!(Method, "POST"), Method::Post);
+/// ```
+/// ```compile_fail
+/// # str_enum::str_enum! {
+/// #     enum Method {
+/// #         Get => "GET",
+/// #     }
+/// # }
+/// let method = str_enum::variant!(Method, "PUT");
+/// ```
+#[macro_export]
+macro_rules! variant {
+    ($ty:ty, $value:literal) => {{
+        const VARIANT: $ty = {
+            let mut idx = 0;
+            loop {
+                if idx == <$ty>::NUM_VARIANTS {
+                    panic!(concat!(
+                        "\"",
+                        $value,
+                        "\" is not a value of `",
+                        stringify!($ty),
+                        "`"
+                    ));
+                }
+                if $crate::const_str_eq(<$ty>::ALL_VALUES[idx], $value, false) {
+                    break match <$ty>::from_index(idx) {
+                        Some(variant) => variant,
+                        None => unreachable!(),
+                    };
+                }
+                idx += 1;
+            }
+        };
+        VARIANT
+    }};
+}
+
+/// Checks the parsing invariants of a str_enum against arbitrary input, panicking if one does not hold.
+/// This is the body of [`fuzz_target!`], usable directly with other fuzzers.
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! fuzz_check {
+    ($ty:ty, $data:expr) => {{
+        let data: &[u8] = $data;
+        $crate::fuzz_check!(@parse $ty, data);
+
+        let deserialized = <$ty as $crate::serde::Deserialize>::deserialize($crate::serde::de::value::BytesDeserializer::<$crate::serde::de::value::Error>::new(data));
+        let expected = ::core::str::from_utf8(data).ok().and_then(|s| <$ty>::try_from_str_scoped(s, $crate::AliasScope::Serde));
+        assert_eq!(deserialized.ok().map(|v| v.as_str()), expected.map(|v| v.as_str()), "deserialize disagrees with try_from_str_scoped");
+    }};
+    (@parse $ty:ty, $data:ident) => {
+        if let Ok(s) = ::core::str::from_utf8($data) {
+            let parsed = <$ty>::try_from_str(s);
+            let detailed = <$ty>::match_detailed(s).map(|(v, _)| v).or_else(<$ty>::fallback);
+            assert_eq!(parsed.as_ref().map(|v| v.as_str()), detailed.as_ref().map(|v| v.as_str()), "match_detailed disagrees with try_from_str");
+
+            if let Some(variant) = parsed {
+                let value = variant.as_str();
+                assert_eq!(<$ty>::try_from_str(value).map(|v| v.as_str()), Some(value), "value does not round trip");
+                assert_eq!($crate::alloc::string::ToString::to_string(&variant), variant.display_str(), "Display disagrees with display_str");
+            }
+        }
+    };
+}
+
+/// Checks the parsing invariants of a str_enum against arbitrary input, panicking if one does not hold.
+/// This is the body of [`fuzz_target!`], usable directly with other fuzzers.
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! fuzz_check {
+    ($ty:ty, $data:expr) => {{
+        let data: &[u8] = $data;
+        $crate::fuzz_check!(@parse $ty, data);
+    }};
+    (@parse $ty:ty, $data:ident) => {
+        if let Ok(s) = ::core::str::from_utf8($data) {
+            let parsed = <$ty>::try_from_str(s);
+            let detailed = <$ty>::match_detailed(s).map(|(v, _)| v).or_else(<$ty>::fallback);
+            assert_eq!(parsed.as_ref().map(|v| v.as_str()), detailed.as_ref().map(|v| v.as_str()), "match_detailed disagrees with try_from_str");
+
+            if let Some(variant) = parsed {
+                let value = variant.as_str();
+                assert_eq!(<$ty>::try_from_str(value).map(|v| v.as_str()), Some(value), "value does not round trip");
+                assert_eq!($crate::alloc::string::ToString::to_string(&variant), variant.display_str(), "Display disagrees with display_str");
             }
         }
+    };
+}
+
+/// Expands to a `cargo fuzz` (libFuzzer) entry point running [`fuzz_check!`] for the given str_enum.
+/// The fuzz crate needs to depend on `libfuzzer-sys` and be `#![no_main]`, like any other fuzz target.
+/// ```ignore
+/// #![no_main]
+/// str_enum::fuzz_target!(my_crate::MyEnum);
+/// ```
+#[macro_export]
+macro_rules! fuzz_target {
+    ($ty:ty) => {
+        ::libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+            $crate::fuzz_check!($ty, data);
+        });
+    };
+}
+
+/// Where an alternate valid form of a variant is accepted.
+/// Forms are accepted everywhere by default, prefix them with `parse` or `serde` to restrict them.
+/// Forms can also be prefixed with `deprecated`, which with the `log` feature logs a warning the first time each one is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AliasScope {
+    /// Accepted everywhere. As a lookup scope, accepts every alternate form.
+    All,
+    /// Accepted by `try_from_str`, `FromStr` and `TryFrom`.
+    Parse,
+    /// Accepted when deserializing with serde.
+    Serde,
+}
+
+impl AliasScope {
+    /// Whether an alternate form with this scope is accepted by a lookup in `scope`
+    pub const fn allows(self, scope: AliasScope) -> bool {
+        matches!(
+            (self, scope),
+            (AliasScope::All, _)
+                | (_, AliasScope::All)
+                | (AliasScope::Parse, AliasScope::Parse)
+                | (AliasScope::Serde, AliasScope::Serde)
+        )
+    }
+}
+
+/// A value given to a variant property with `{ key: value }`, read back through `strum::EnumProperty`.
+#[cfg(feature = "strum")]
+#[doc(hidden)]
+pub trait PropertyValue: Copy {
+    fn as_str(self) -> Option<&'static str> {
+        None
+    }
+
+    fn as_int(self) -> Option<i64> {
+        None
+    }
+
+    fn as_bool(self) -> Option<bool> {
+        None
+    }
+}
+
+#[cfg(feature = "strum")]
+impl PropertyValue for &'static str {
+    fn as_str(self) -> Option<&'static str> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "strum")]
+impl PropertyValue for i64 {
+    fn as_int(self) -> Option<i64> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "strum")]
+impl PropertyValue for bool {
+    fn as_bool(self) -> Option<bool> {
+        Some(self)
+    }
+}
 
-        impl<'de> $crate::serde::Deserialize<'de> for $ty {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: $crate::serde::Deserializer<'de>,
-            {
-                let val = <std::borrow::Cow<'_, str> as $crate::serde::Deserialize>::deserialize(deserializer)?;
-                $ty::try_from_str(&val).ok_or_else(|| $crate::serde::de::Error::invalid_value($crate::serde::de::Unexpected::Str(&val), &$ty::SERDE_EXPECTED_STR))
+/// The registry entry for a str_enum's async-graphql scalar, shared by its `InputType` and `OutputType` impls.
+#[cfg(feature = "async-graphql")]
+#[doc(hidden)]
+pub fn graphql_scalar_meta<T: async_graphql::ScalarType>(
+    name: &str,
+    expected: &str,
+) -> async_graphql::registry::MetaType {
+    async_graphql::registry::MetaType::Scalar {
+        name: alloc::string::String::from(name),
+        description: Some(alloc::format!("One of {expected}")),
+        is_valid: Some(alloc::sync::Arc::new(|value| T::is_valid(value))),
+        visible: None,
+        inaccessible: false,
+        tags: alloc::vec::Vec::new(),
+        specified_by_url: None,
+        directive_invocations: alloc::vec::Vec::new(),
+        requires_scopes: alloc::vec::Vec::new(),
+    }
+}
+
+/// Whether every value can be a `HeaderValue`, for the compile-time check on `#[http]` enums.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub const fn all_header_values(values: &[&str]) -> bool {
+    let mut idx = 0;
+    while idx < values.len() {
+        let bytes = values[idx].as_bytes();
+        let mut byte = 0;
+        while byte < bytes.len() {
+            // the same check as `HeaderValue::from_bytes`
+            if bytes[byte] < 32 && bytes[byte] != b'\t' || bytes[byte] == 127 {
+                return false;
             }
+            byte += 1;
         }
-    };
+        idx += 1;
+    }
+    true
 }
 
-#[macro_export]
-#[cfg(not(feature = "serde"))]
-macro_rules! str_enum_serde {
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {};
+/// `value` as a `HeaderValue`, which `all_header_values` has already checked it can be.
+#[cfg(feature = "http")]
+#[doc(hidden)]
+pub fn header_value(value: &'static str) -> http::HeaderValue {
+    // `from_static` only takes visible ASCII, `from_bytes` keeps anything else as opaque bytes
+    if value.is_ascii() {
+        http::HeaderValue::from_static(value)
+    } else {
+        http::HeaderValue::from_bytes(value.as_bytes()).expect("checked by `all_header_values`")
+    }
 }
 
-#[macro_export]
-macro_rules! str_enum {
-    ($(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
-        $crate::str_enum_base!(
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
-            }
-        );
+/// The value of the variant at `INDEX`, as a constant `MyEnum!(pattern)` can match on. You do not need this.
+#[doc(hidden)]
+pub trait VariantValue<const INDEX: usize> {
+    const VALUE: &'static str;
+}
 
-        $crate::str_enum_strum!(
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
-            }
-        );
+/// The index of the variant named `name` in `names`, the `VARIANT_NAMES` of its enum.
+#[doc(hidden)]
+pub const fn variant_index(names: &[&str], name: &str) -> usize {
+    let mut idx = 0;
+    while !const_str_eq(names[idx], name, false) {
+        idx += 1;
+    }
+    idx
+}
 
-        $crate::str_enum_serde!(
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
-            }
-        );
+/// `name` without the `r#` of a raw identifier, as `stringify!` keeps it.
+#[doc(hidden)]
+pub const fn unraw(name: &'static str) -> &'static str {
+    match name.as_bytes() {
+        [b'r', b'#', ..] => name.split_at(2).1,
+        _ => name,
+    }
+}
 
-        $crate::str_enum_try_from_str!{
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
-            }
+/// The bytes of `s` followed by a NUL byte, for `as_c_str`. `N` must be `s.len() + 1`.
+#[doc(hidden)]
+pub const fn nul_terminated<const N: usize>(s: &str) -> [u8; N] {
+    let mut buf = [0u8; N];
+    let bytes = s.as_bytes();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        buf[idx] = bytes[idx];
+        idx += 1
+    }
+    buf
+}
+
+/// The candidate with the smallest edit distance to `input`, as long as that is at most a third of its length (and at least 1).
+#[doc(hidden)]
+pub fn closest_spelling(
+    input: &str,
+    candidates: impl Iterator<Item = &'static str>,
+) -> Option<&'static str> {
+    let max_distance = (input.chars().count() / 3).max(1);
+    let mut closest = None;
+    let mut closest_distance = max_distance + 1;
+    for candidate in candidates {
+        let distance = edit_distance(input, candidate);
+        if distance < closest_distance {
+            closest = Some(candidate);
+            closest_distance = distance;
+        }
+    }
+    closest
+}
+
+/// Levenshtein distance between `a` and `b`, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let mut row: alloc::vec::Vec<usize> = (0..=b.chars().count()).collect();
+    for (a_idx, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = a_idx + 1;
+        for (b_idx, b_char) in b.chars().enumerate() {
+            let substitution = diagonal + usize::from(a_char != b_char);
+            diagonal = row[b_idx + 1];
+            row[b_idx + 1] = substitution.min(row[b_idx] + 1).min(diagonal + 1);
         }
+    }
+    row[row.len() - 1]
+}
 
-        $crate::str_enum_base!(FromStr
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
+/// Writes `template` into `buf` with every `{values}` replaced by `values`, returning the length of the result.
+/// With an empty `buf` nothing is written, which gives the length to size `buf` with.
+#[doc(hidden)]
+pub const fn fill_template(template: &str, values: &[u8], buf: &mut [u8]) -> usize {
+    const PLACEHOLDER: &[u8] = b"{values}";
+    let template = template.as_bytes();
+    let mut template_idx = 0;
+    let mut idx = 0;
+    while template_idx < template.len() {
+        let mut placeholder_idx = 0;
+        while placeholder_idx < PLACEHOLDER.len()
+            && template_idx + placeholder_idx < template.len()
+            && template[template_idx + placeholder_idx] == PLACEHOLDER[placeholder_idx]
+        {
+            placeholder_idx += 1
+        }
+        if placeholder_idx == PLACEHOLDER.len() {
+            let mut values_idx = 0;
+            while values_idx < values.len() {
+                if !buf.is_empty() {
+                    buf[idx] = values[values_idx];
+                }
+                idx += 1;
+                values_idx += 1
             }
-        );
-    };
-    (#[phf] $(#[error_type($error_ty:ident)])? $(#[derive($($derive_trait:ident),* $(,)?)])? $(#[repr($repr:ty)])? $vis:vis enum $ty:ident { $($variant:ident $(= $variant_repr:literal)? => $val:literal $(($($other_valid:literal),* $(,)?))?),* $(,)? }) => {
-        $crate::str_enum_base!(
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
+            template_idx += PLACEHOLDER.len()
+        } else {
+            if !buf.is_empty() {
+                buf[idx] = template[template_idx];
             }
-        );
+            idx += 1;
+            template_idx += 1
+        }
+    }
+    idx
+}
 
-        $crate::str_enum_strum!(
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
+/// Whether two values in `spellings` are equal, or a valid form is equal to a value or valid form of another variant that is
+/// accepted in the same scope. Each spelling comes with the value of its variant, its scope and whether it is the value itself.
+#[doc(hidden)]
+pub const fn spellings_collide(spellings: &[(&str, &str, AliasScope, bool)]) -> bool {
+    let mut first = 0;
+    while first < spellings.len() {
+        let (first_spelling, first_owner, first_scope, first_is_value) = spellings[first];
+        let mut second = first + 1;
+        while second < spellings.len() {
+            let (second_spelling, second_owner, second_scope, second_is_value) = spellings[second];
+            let same_variant = const_str_eq(first_owner, second_owner, false)
+                && !(first_is_value && second_is_value);
+            if !same_variant
+                && first_scope.allows(second_scope)
+                && const_str_eq(first_spelling, second_spelling, false)
+            {
+                return true;
             }
-        );
+            second += 1
+        }
+        first += 1
+    }
+    false
+}
 
-        $crate::str_enum_serde!(
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
-            }
-        );
+/// `spellings` sorted by spelling, for `try_from_str` lookups. `N` must be `spellings.len()`.
+/// With `by_length` they're ordered by length first, otherwise like `str`'s `Ord` (for `#[parse(sorted)]`).
+#[doc(hidden)]
+pub const fn sort_spellings<T: Copy, const N: usize>(
+    spellings: &[(&'static str, T, AliasScope, bool)],
+    by_length: bool,
+) -> [(&'static str, T, AliasScope, bool); N] {
+    let mut sorted = match spellings.first_chunk::<N>() {
+        Some(spellings) => *spellings,
+        None => panic!("`N` must be `spellings.len()`"),
+    };
+    let mut idx = 1;
+    while idx < N {
+        sorted[idx] = spellings[idx];
+        let mut sorted_idx = idx;
+        while sorted_idx > 0
+            && spelling_lt(sorted[sorted_idx].0, sorted[sorted_idx - 1].0, by_length)
+        {
+            let swapped = sorted[sorted_idx];
+            sorted[sorted_idx] = sorted[sorted_idx - 1];
+            sorted[sorted_idx - 1] = swapped;
+            sorted_idx -= 1
+        }
+        idx += 1
+    }
+    sorted
+}
 
-        $crate::str_enum_try_from_str!{
-            #[phf]
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
-            }
+/// Binary searches spellings sorted by `sort_spellings` with the same `by_length` for one equal to `s` that is accepted in `scope`.
+/// Ordered by length, the search only narrows down to the spellings with the length and first byte of `s`, which are then compared whole.
+#[doc(hidden)]
+pub fn find_sorted<T: Copy>(
+    sorted: &[(&'static str, T, AliasScope, bool)],
+    s: &str,
+    scope: AliasScope,
+    by_length: bool,
+) -> Option<(&'static str, T, bool)> {
+    let bucket = |spelling: &str| (spelling.len(), spelling.as_bytes().first().copied());
+    let start = sorted.partition_point(|(spelling, ..)| match by_length {
+        true => bucket(spelling) < bucket(s),
+        false => spelling_lt(spelling, s, false),
+    });
+    sorted[start..]
+        .iter()
+        .take_while(|(spelling, ..)| match by_length {
+            true => bucket(spelling) == bucket(s),
+            false => *spelling == s,
+        })
+        .find(|(spelling, _, alias_scope, _)| spelling_eq(spelling, s) && alias_scope.allows(scope))
+        .map(|&(spelling, variant, _, deprecated)| (spelling, variant, deprecated))
+}
+
+/// The final comparison of `find_sorted`, through `memchr`'s word-at-a-time equality check with the `memchr` feature
+#[cfg(feature = "memchr")]
+fn spelling_eq(spelling: &str, s: &str) -> bool {
+    memchr::arch::all::is_equal(spelling.as_bytes(), s.as_bytes())
+}
+
+/// The final comparison of `find_sorted`
+#[cfg(not(feature = "memchr"))]
+fn spelling_eq(spelling: &str, s: &str) -> bool {
+    spelling == s
+}
+
+/// `a < b` in const contexts, comparing bytes like `str`'s `Ord`, after comparing lengths if `by_length`.
+/// Ordered by length, spellings of the same length are grouped and then ordered by their first byte.
+const fn spelling_lt(a: &str, b: &str, by_length: bool) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if by_length && a.len() != b.len() {
+        return a.len() < b.len();
+    }
+    let mut idx = 0;
+    while idx < a.len() && idx < b.len() {
+        if a[idx] != b[idx] {
+            return a[idx] < b[idx];
         }
+        idx += 1
+    }
+    a.len() < b.len()
+}
 
-        $crate::str_enum_base!(FromStr
-            $(#[error_type($error_ty)])?
-            $(#[derive($($derive_trait,)*)])?
-            $(#[repr($repr)])?
-            $vis enum $ty {
-                $($variant $(= $variant_repr)? => $val $(($($other_valid),*))?,)*
+/// `a == b` (or `a.eq_ignore_ascii_case(b)`) in const contexts, for `try_from_str_const`.
+#[doc(hidden)]
+pub const fn const_str_eq(a: &str, b: &str, ignore_ascii_case: bool) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut idx = 0;
+    while idx < a.len() {
+        let matches = if ignore_ascii_case {
+            a[idx].eq_ignore_ascii_case(&b[idx])
+        } else {
+            a[idx] == b[idx]
+        };
+        if !matches {
+            return false;
+        }
+        idx += 1
+    }
+    true
+}
+
+/// How `#[rename_all = "..."]` derives a value from a variant name, following the serde rules of the same names.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    /// Length of `name` once renamed.
+    pub const fn renamed_len(self, name: &str) -> usize {
+        self.write(name, &mut [])
+    }
+
+    /// `name` renamed, `N` has to be `self.renamed_len(name)`.
+    pub const fn rename<const N: usize>(self, name: &str) -> [u8; N] {
+        let mut buf = [0u8; N];
+        self.write(name, &mut buf);
+        buf
+    }
+
+    /// Writes `name` renamed into `buf` if it is non-empty, returning the length either way.
+    const fn write(self, name: &str, buf: &mut [u8]) -> usize {
+        let bytes = name.as_bytes();
+        // raw identifiers are stringified with their prefix
+        let mut name_idx = if bytes.len() > 2 && bytes[0] == b'r' && bytes[1] == b'#' {
+            2
+        } else {
+            0
+        };
+        let start = name_idx;
+        let separator = match self {
+            RenameRule::SnakeCase | RenameRule::ScreamingSnakeCase => b'_',
+            RenameRule::KebabCase | RenameRule::ScreamingKebabCase => b'-',
+            _ => 0,
+        };
+
+        let mut idx = 0;
+        while name_idx < bytes.len() {
+            let byte = bytes[name_idx];
+            if separator != 0 && byte.is_ascii_uppercase() && name_idx != start {
+                if !buf.is_empty() {
+                    buf[idx] = separator;
+                }
+                idx += 1;
             }
-        );
-    };
+            let byte = match self {
+                RenameRule::LowerCase | RenameRule::SnakeCase | RenameRule::KebabCase => {
+                    byte.to_ascii_lowercase()
+                }
+                RenameRule::UpperCase
+                | RenameRule::ScreamingSnakeCase
+                | RenameRule::ScreamingKebabCase => byte.to_ascii_uppercase(),
+                RenameRule::CamelCase if name_idx == start => byte.to_ascii_lowercase(),
+                RenameRule::PascalCase | RenameRule::CamelCase => byte,
+            };
+            if !buf.is_empty() {
+                buf[idx] = byte;
+            }
+            idx += 1;
+            name_idx += 1
+        }
+        idx
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Utf8EnumError<E> {
-    Utf8(std::str::Utf8Error),
+    Utf8(core::str::Utf8Error),
     InvalidVariant(E),
 }
 
-impl<E> std::fmt::Display for Utf8EnumError<E>
+impl<E> core::fmt::Display for Utf8EnumError<E>
 where
-    E: std::fmt::Display,
+    E: core::fmt::Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Utf8EnumError::Utf8(utf8_error) => utf8_error.fmt(f),
             Utf8EnumError::InvalidVariant(variant_error) => variant_error.fmt(f),
@@ -755,4 +4812,316 @@ where
     }
 }
 
-impl<E> std::error::Error for Utf8EnumError<E> where E: std::error::Error {}
+impl<E> core::error::Error for Utf8EnumError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Utf8EnumError::Utf8(utf8_error) => Some(utf8_error),
+            Utf8EnumError::InvalidVariant(variant_error) => Some(variant_error),
+        }
+    }
+}
+
+impl<E> From<core::str::Utf8Error> for Utf8EnumError<E> {
+    fn from(utf8_error: core::str::Utf8Error) -> Self {
+        Utf8EnumError::Utf8(utf8_error)
+    }
+}
+
+impl<E> Utf8EnumError<E> {
+    /// The enum's error if the input was valid UTF-8, otherwise the UTF-8 error.
+    pub fn into_inner(self) -> Result<E, core::str::Utf8Error> {
+        match self {
+            Utf8EnumError::Utf8(utf8_error) => Err(utf8_error),
+            Utf8EnumError::InvalidVariant(variant_error) => Ok(variant_error),
+        }
+    }
+
+    /// The UTF-8 error, if the input wasn't valid UTF-8.
+    pub const fn as_utf8(&self) -> Option<&core::str::Utf8Error> {
+        match self {
+            Utf8EnumError::Utf8(utf8_error) => Some(utf8_error),
+            Utf8EnumError::InvalidVariant(_) => None,
+        }
+    }
+
+    /// The enum's error, if the input was valid UTF-8 but not a valid value.
+    pub const fn as_invalid_variant(&self) -> Option<&E> {
+        match self {
+            Utf8EnumError::Utf8(_) => None,
+            Utf8EnumError::InvalidVariant(variant_error) => Some(variant_error),
+        }
+    }
+
+    /// Maps the enum's error with `f`, leaving a UTF-8 error as it is.
+    pub fn map_invalid<F>(self, f: impl FnOnce(E) -> F) -> Utf8EnumError<F> {
+        match self {
+            Utf8EnumError::Utf8(utf8_error) => Utf8EnumError::Utf8(utf8_error),
+            Utf8EnumError::InvalidVariant(variant_error) => {
+                Utf8EnumError::InvalidVariant(f(variant_error))
+            }
+        }
+    }
+}
+
+/// Serializes as an externally tagged enum, `{"Utf8": "<message>"}` or `{"InvalidVariant": <error>}`.
+#[cfg(feature = "serde")]
+impl<E> serde::Serialize for Utf8EnumError<E>
+where
+    E: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Utf8EnumError::Utf8(utf8_error) => serializer.serialize_newtype_variant(
+                "Utf8EnumError",
+                0,
+                "Utf8",
+                &alloc::string::ToString::to_string(utf8_error),
+            ),
+            Utf8EnumError::InvalidVariant(variant_error) => serializer.serialize_newtype_variant(
+                "Utf8EnumError",
+                1,
+                "InvalidVariant",
+                variant_error,
+            ),
+        }
+    }
+}
+
+/// Deserializes from a borrowed string without allocating, mapping it with a closure that hands back the part of the input it rejected.
+/// Used by the generated serde impls. Bytes that aren't UTF-8 are an invalid value.
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+pub struct StrVisitor<T, F> {
+    expected: &'static str,
+    map: F,
+    value: core::marker::PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T, F> StrVisitor<T, F>
+where
+    F: for<'a> FnOnce(&'a str) -> Result<T, &'a str>,
+{
+    pub fn new(expected: &'static str, map: F) -> Self {
+        Self {
+            expected,
+            map,
+            value: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, F> serde::de::Visitor<'de> for StrVisitor<T, F>
+where
+    F: for<'a> FnOnce(&'a str) -> Result<T, &'a str>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(self.expected)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<T, E>
+    where
+        E: serde::de::Error,
+    {
+        let expected = self.expected;
+        (self.map)(v)
+            .map_err(|rejected| E::invalid_value(serde::de::Unexpected::Str(rejected), &expected))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<T, E>
+    where
+        E: serde::de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(
+                serde::de::Unexpected::Bytes(v),
+                &self.expected,
+            )),
+        }
+    }
+}
+
+/// Iterator over every variant of a str_enum in declaration order, from `MyEnum::iter()`.
+pub struct Variants<T: 'static> {
+    constructors: core::slice::Iter<'static, fn() -> T>,
+}
+
+impl<T> Variants<T> {
+    #[doc(hidden)]
+    pub fn new(constructors: &'static [fn() -> T]) -> Self {
+        Variants {
+            constructors: constructors.iter(),
+        }
+    }
+}
+
+impl<T> Clone for Variants<T> {
+    fn clone(&self) -> Self {
+        Variants {
+            constructors: self.constructors.clone(),
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for Variants<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Variants")
+            .field("remaining", &self.constructors.len())
+            .finish()
+    }
+}
+
+impl<T> Iterator for Variants<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.constructors.next().map(|variant| variant())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.constructors.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.constructors.nth(n).map(|variant| variant())
+    }
+}
+
+impl<T> DoubleEndedIterator for Variants<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.constructors.next_back().map(|variant| variant())
+    }
+}
+
+impl<T> ExactSizeIterator for Variants<T> {}
+
+impl<T> core::iter::FusedIterator for Variants<T> {}
+
+/// Error from `parse_list`, holding the first element that didn't parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseListError {
+    /// The 0-based index of the element in the list.
+    pub index: usize,
+    /// The element, with surrounding whitespace trimmed.
+    pub value: alloc::string::String,
+}
+
+impl core::fmt::Display for ParseListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "element {}: invalid value {:?}", self.index, self.value)
+    }
+}
+
+impl core::error::Error for ParseListError {}
+
+/// Picks the integer backing an `#[enum_set]` of `N` variants.
+#[doc(hidden)]
+pub struct SetWidth<const N: usize>;
+
+#[doc(hidden)]
+pub trait SetRepr {
+    type Bits: SetBits;
+}
+
+/// The integers an `#[enum_set]` can be stored in.
+#[doc(hidden)]
+pub trait SetBits:
+    Copy
+    + Eq
+    + core::hash::Hash
+    + Default
+    + core::ops::BitOr<Output = Self>
+    + core::ops::BitAnd<Output = Self>
+    + core::ops::BitOrAssign
+    + core::ops::BitAndAssign
+    + core::ops::Not<Output = Self>
+{
+    fn bit(idx: usize) -> Self;
+    fn count(self) -> u32;
+}
+
+macro_rules! set_bits {
+    ($($bits:ty: [$($width:literal)*],)*) => {
+        $(
+            impl SetBits for $bits {
+                fn bit(idx: usize) -> $bits {
+                    1 << idx
+                }
+
+                fn count(self) -> u32 {
+                    self.count_ones()
+                }
+            }
+
+            $(
+                impl SetRepr for SetWidth<$width> {
+                    type Bits = $bits;
+                }
+            )*
+        )*
+    };
+}
+
+set_bits! {
+    u8: [0 1 2 3 4 5 6 7 8],
+    u16: [9 10 11 12 13 14 15 16],
+    u32: [17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32],
+    u64: [33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64],
+    u128: [65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128],
+}
+
+/// Error from `parse_lines`, holding the 1-based line number it occurred on.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum LineParseError {
+    Io {
+        line: usize,
+        error: std::io::Error,
+    },
+    InvalidValue {
+        line: usize,
+        value: alloc::string::String,
+    },
+}
+
+#[cfg(feature = "std")]
+impl LineParseError {
+    /// The 1-based line number the error occurred on.
+    pub fn line(&self) -> usize {
+        match self {
+            LineParseError::Io { line, .. } | LineParseError::InvalidValue { line, .. } => *line,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for LineParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LineParseError::Io { line, error } => write!(f, "line {line}: {error}"),
+            LineParseError::InvalidValue { line, value } => {
+                write!(f, "line {line}: invalid value {value:?}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for LineParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            LineParseError::Io { error, .. } => Some(error),
+            LineParseError::InvalidValue { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,332 @@
+#![forbid(unsafe_code)]
+//! `#[derive(StrEnum)]`, an alternative to `str_enum::str_enum!` for enums declared as normal Rust.
+//! Use it through the `derive` feature of `str_enum`, which re-exports it; the generated impls are the same as the macro's.
+//! ```ignore
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, str_enum::StrEnum)]
+//! #[str_enum(error_type(MyError), parse(case_insensitive), rename_all = "kebab-case")] // any of the macro's attributes, plus `phf`
+//! #[repr(u8)] // picked up like `#[repr]` in the macro
+//! enum MyEnum {
+//!     #[str("Value0")]
+//!     Variant0,
+//!     #[str("Value1", "value1", parse "v1")] // other valid forms follow the value, like in brackets in the macro
+//!     Variant1 = 3,
+//!     VariantTwo, // no `#[str]`, so the value is the name, here "variant-two" after `rename_all`
+//!     #[str("Internal")]
+//!     #[str_enum(parse(skip), description = "Not for users")] // `description` is the `? "..."` of the macro
+//!     Internal,
+//!     #[str_enum(predicate = is_blank)] // `is_empty` would clash with the method every str_enum has
+//!     Empty,
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, quote};
+use syn::{Data, DeriveInput, Error, Fields, Lit, parenthesized, parse_macro_input};
+
+#[proc_macro_derive(StrEnum, attributes(str_enum, str))]
+pub fn derive_str_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Attributes of the enum, in the order `str_enum_impls!` expects them
+#[derive(Default)]
+struct EnumOptions {
+    phf: bool,
+    error_type: Option<TokenStream2>,
+    repr: Option<TokenStream2>,
+    serde: Option<TokenStream2>,
+    lasso: Option<TokenStream2>,
+    parse: Option<TokenStream2>,
+    str_type: Option<TokenStream2>,
+    serde_with: Option<TokenStream2>,
+    rename_all: Option<syn::LitStr>,
+    catch_all: Option<TokenStream2>,
+    skip_impls: Option<TokenStream2>,
+    cmp: Option<TokenStream2>,
+    enum_map: Option<TokenStream2>,
+    enum_set: Option<TokenStream2>,
+    constant: Option<TokenStream2>,
+    http: bool,
+}
+
+impl ToTokens for EnumOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        if self.phf {
+            tokens.extend(quote!(#[phf]));
+        }
+        let attrs = [
+            ("error_type", &self.error_type),
+            ("repr", &self.repr),
+            ("serde", &self.serde),
+            ("lasso", &self.lasso),
+            ("parse", &self.parse),
+            ("str_type", &self.str_type),
+            ("serde_with", &self.serde_with),
+        ];
+        for (name, args) in attrs {
+            if let Some(args) = args {
+                let name = syn::Ident::new(name, proc_macro2::Span::call_site());
+                tokens.extend(quote!(#[#name(#args)]));
+            }
+        }
+        if let Some(rename_all) = &self.rename_all {
+            tokens.extend(quote!(#[rename_all = #rename_all]));
+        }
+        if let Some(catch_all) = &self.catch_all {
+            tokens.extend(quote!(#[catch_all(#catch_all)]));
+        }
+        if let Some(skip_impls) = &self.skip_impls {
+            tokens.extend(quote!(#[skip_impls(#skip_impls)]));
+        }
+        if let Some(cmp) = &self.cmp {
+            tokens.extend(quote!(#[cmp(#cmp)]));
+        }
+        if let Some(enum_map) = &self.enum_map {
+            tokens.extend(quote!(#[enum_map(#enum_map)]));
+        }
+        if let Some(enum_set) = &self.enum_set {
+            tokens.extend(quote!(#[enum_set(#enum_set)]));
+        }
+        if let Some(constant) = &self.constant {
+            tokens.extend(quote!(#[constant(#constant)]));
+        }
+        if self.http {
+            tokens.extend(quote!(#[http]));
+        }
+    }
+}
+
+const INTEGER_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+fn parenthesized_tokens(input: syn::parse::ParseStream) -> syn::Result<TokenStream2> {
+    let content;
+    parenthesized!(content in input);
+    content.parse()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "StrEnum can only be derived for enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "StrEnum can not be derived for generic enums",
+        ));
+    }
+
+    let mut options = EnumOptions::default();
+    for attr in &input.attrs {
+        if attr.path().is_ident("repr") {
+            // layout reprs like `C` don't change the discriminant type
+            attr.parse_nested_meta(|meta| {
+                if INTEGER_REPRS.iter().any(|repr| meta.path.is_ident(repr)) {
+                    options.repr = Some(meta.path.to_token_stream());
+                } else if meta.input.peek(syn::token::Paren) {
+                    parenthesized_tokens(meta.input)?;
+                }
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("str_enum") {
+            attr.parse_nested_meta(|meta| {
+                let slot = if meta.path.is_ident("phf") {
+                    options.phf = true;
+                    return Ok(());
+                } else if meta.path.is_ident("error_type") {
+                    &mut options.error_type
+                } else if meta.path.is_ident("serde") {
+                    &mut options.serde
+                } else if meta.path.is_ident("lasso") {
+                    &mut options.lasso
+                } else if meta.path.is_ident("parse") {
+                    &mut options.parse
+                } else if meta.path.is_ident("str_type") {
+                    &mut options.str_type
+                } else if meta.path.is_ident("serde_with") {
+                    &mut options.serde_with
+                } else if meta.path.is_ident("rename_all") {
+                    options.rename_all = Some(meta.value()?.parse()?);
+                    return Ok(());
+                } else if meta.path.is_ident("catch_all") {
+                    &mut options.catch_all
+                } else if meta.path.is_ident("skip_impls") {
+                    &mut options.skip_impls
+                } else if meta.path.is_ident("cmp") {
+                    &mut options.cmp
+                } else if meta.path.is_ident("enum_map") {
+                    &mut options.enum_map
+                } else if meta.path.is_ident("enum_set") {
+                    &mut options.enum_set
+                } else if meta.path.is_ident("constant") {
+                    &mut options.constant
+                } else if meta.path.is_ident("http") {
+                    options.http = true;
+                    return Ok(());
+                } else {
+                    return Err(meta.error(
+                        "unknown str_enum attribute, expected one of `phf`, `error_type`, `serde`, `lasso`, `parse`, `str_type`, `serde_with`, `rename_all`, `catch_all`, `skip_impls`, `cmp`, `enum_map`, `enum_set`, `constant` or `http`",
+                    ));
+                };
+                *slot = Some(parenthesized_tokens(meta.input)?);
+                Ok(())
+            })?;
+        }
+    }
+
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut predicate_names = Vec::<syn::Ident>::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "StrEnum variants can not have fields",
+            ));
+        }
+
+        let mut value = None;
+        let mut other_valid = None;
+        let mut parse = None;
+        let mut description = None;
+        let mut constant = None;
+        let mut predicate = None;
+        let mut properties = Vec::new();
+        let mut docs = Vec::new();
+        let mut deprecated = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("doc") {
+                // forwarded for `documentation()`, docs that aren't a plain literal are left out
+                if let syn::Expr::Lit(doc) = &attr.meta.require_name_value()?.value {
+                    docs.push(quote!(#[doc = #doc]));
+                }
+            } else if attr.path().is_ident("deprecated") {
+                // forwarded for `is_deprecated()` and the deprecation warning when parsing
+                deprecated = Some(attr);
+            } else if attr.path().is_ident("str") {
+                let mut tokens = attr.meta.require_list()?.tokens.clone().into_iter();
+                match tokens.next() {
+                    Some(TokenTree::Literal(literal))
+                        if matches!(Lit::new(literal.clone()), Lit::Str(_)) =>
+                    {
+                        value = Some(literal)
+                    }
+                    _ => {
+                        return Err(Error::new_spanned(
+                            attr,
+                            "expected the value as a string literal first, as in `#[str(\"value\")]`",
+                        ));
+                    }
+                }
+                match tokens.next() {
+                    None => {}
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {
+                        other_valid = Some(tokens.collect::<TokenStream2>())
+                    }
+                    Some(other) => {
+                        return Err(Error::new_spanned(
+                            other,
+                            "expected `,` before other valid forms",
+                        ));
+                    }
+                }
+            } else if attr.path().is_ident("str_enum") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("parse") {
+                        parse = Some(parenthesized_tokens(meta.input)?);
+                        Ok(())
+                    } else if meta.path.is_ident("description") {
+                        description = Some(meta.value()?.parse::<syn::LitStr>()?);
+                        Ok(())
+                    } else if meta.path.is_ident("constant") {
+                        constant = Some(meta.value()?.parse::<Lit>()?);
+                        Ok(())
+                    } else if meta.path.is_ident("predicate") {
+                        predicate = Some(meta.value()?.parse::<syn::Ident>()?);
+                        Ok(())
+                    } else if meta.path.is_ident("properties") {
+                        meta.parse_nested_meta(|property| {
+                            let key = property.path.require_ident()?.clone();
+                            let value: Lit = property.value()?.parse()?;
+                            properties.push(quote!(#key: #value));
+                            Ok(())
+                        })
+                    } else {
+                        Err(meta.error(
+                            "unknown str_enum variant attribute, expected `parse`, `description`, `constant`, `predicate` or `properties`",
+                        ))
+                    }
+                })?;
+            }
+        }
+
+        let ident = &variant.ident;
+        let predicate = predicate
+            .unwrap_or_else(|| syn::Ident::new(&format!("is_{}", snake_case(ident)), ident.span()));
+        if INHERENT_PREDICATES
+            .iter()
+            .any(|inherent| predicate == inherent)
+        {
+            return Err(Error::new(
+                predicate.span(),
+                format!(
+                    "`{predicate}` clashes with the `{predicate}` method every str_enum has, rename it with `#[str_enum(predicate = ...)]`"
+                ),
+            ));
+        }
+        if predicate_names.contains(&predicate) {
+            return Err(Error::new(
+                predicate.span(),
+                format!(
+                    "`{predicate}` is the predicate of an earlier variant too, rename one with `#[str_enum(predicate = ...)]`"
+                ),
+            ));
+        }
+        predicate_names.push(predicate.clone());
+
+        let parse = parse.map(|parse| quote!(#[parse(#parse)]));
+        let value = value.map(|value| quote!(=> #value));
+        let constant = constant.map(|constant| quote!(: #constant));
+        let other_valid = other_valid.map(|other_valid| quote!((#other_valid)));
+        let properties = (!properties.is_empty()).then(|| quote!({ #(#properties),* }));
+        let description = description.map(|description| quote!(? #description));
+        variants.push(
+            quote!(#(#docs)* #parse #deprecated #[predicate(#predicate)] #ident #value #constant #other_valid #properties #description,),
+        );
+    }
+
+    let vis = &input.vis;
+    let ident = &input.ident;
+    Ok(quote! {
+        ::str_enum::str_enum_impls! {
+            #options
+            #vis enum #ident {
+                #(#variants)*
+            }
+        }
+    })
+}
+
+/// Inherent `is_*` methods of every str_enum, which a variant's predicate can't be named
+const INHERENT_PREDICATES: &[&str] = &["is_empty", "is_deprecated"];
+
+/// `VariantName` as `variant_name`, for the `is_*` predicates
+fn snake_case(ident: &syn::Ident) -> String {
+    let name = ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (idx, c) in name.char_indices() {
+        if c.is_uppercase() && idx != 0 && !snake.ends_with('_') {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "async-graphql")]
+
+use str_enum::{
+    async_graphql::{InputType, OutputType, ScalarType, Value},
+    str_enum,
+};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Colour {
+        Red => "red"("crimson"),
+        LightBlue => "light-blue",
+    }
+}
+
+#[test]
+fn test_scalar_parse() {
+    let value = Value::String("crimson".to_string());
+    assert!(<Colour as ScalarType>::is_valid(&value));
+    assert_eq!(<Colour as ScalarType>::parse(value).ok(), Some(Colour::Red));
+
+    let error = <Colour as InputType>::parse(Some(Value::String("green".to_string()))).unwrap_err();
+    assert_eq!(
+        error.into_server_error(Default::default()).message,
+        r#"Failed to parse "Colour": invalid Colour: expected one of "red" or "light-blue""#
+    );
+    assert!(<Colour as InputType>::parse(Some(Value::Boolean(true))).is_err());
+    assert!(<Colour as InputType>::parse(None).is_err());
+}
+
+#[test]
+fn test_scalar_output() {
+    assert_eq!(
+        <Colour as InputType>::to_value(&Colour::LightBlue),
+        Value::String("light-blue".to_string())
+    );
+    assert_eq!(<Colour as OutputType>::type_name(), "Colour");
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "caseless")]
+
+use str_enum::str_enum;
+
+str_enum! {
+    #[error_type(StreetError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[parse(case_fold)]
+    pub(crate) enum Street {
+        Strasse => "Straße",
+        Gasse => "Gasse"("Gäßchen"),
+    }
+}
+
+#[test]
+fn test_case_fold_exact() {
+    assert_eq!(Street::try_from_str("Straße"), Some(Street::Strasse));
+    assert_eq!(Street::try_from_str("Gäßchen"), Some(Street::Gasse));
+}
+
+#[test]
+fn test_case_fold_full_folding() {
+    assert_eq!(Street::try_from_str("STRASSE"), Some(Street::Strasse));
+    assert_eq!(Street::try_from_str("straße"), Some(Street::Strasse));
+    assert_eq!(Street::try_from_str("GÄSSCHEN"), Some(Street::Gasse));
+    assert_eq!("gasse".parse::<Street>().unwrap(), Street::Gasse);
+}
+
+#[test]
+fn test_case_fold_match_detailed() {
+    assert_eq!(
+        Street::match_detailed("GÄSSCHEN"),
+        Some((Street::Gasse, "Gäßchen"))
+    );
+}
+
+#[test]
+fn test_case_fold_invalid() {
+    assert_eq!(Street::try_from_str("Strase"), None);
+    assert!("".parse::<Street>().is_err());
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[parse(case_fold)]
+    pub(crate) enum Locale {
+        Greek => "Ελληνικά",
+        Russian => "Русский",
+        Spanish => "Español",
+    }
+}
+
+#[test]
+fn test_case_fold_non_ascii_values() {
+    assert_eq!(Locale::try_from_str("ΕΛΛΗΝΙΚΆ"), Some(Locale::Greek));
+    assert_eq!(Locale::try_from_str("русский"), Some(Locale::Russian));
+    assert_eq!(Locale::try_from_str("ESPAÑOL"), Some(Locale::Spanish));
+    assert_eq!(Locale::try_from_str("ESPANOL"), None);
+}
+
+#[cfg(feature = "phf")]
+mod phf {
+    str_enum::str_enum! {
+        #[phf]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[parse(case_fold)]
+        pub(crate) enum PhfStreet {
+            Strasse => "Straße",
+            Gasse => "Gasse",
+        }
+    }
+
+    #[test]
+    fn test_phf_case_fold() {
+        assert_eq!(PhfStreet::try_from_str("Straße"), Some(PhfStreet::Strasse));
+        assert_eq!(PhfStreet::try_from_str("STRASSE"), Some(PhfStreet::Strasse));
+        assert_eq!(PhfStreet::try_from_str("Strase"), None);
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "clap")]
+
+use str_enum::{clap::ValueEnum, str_enum};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Level {
+        Low => "low"("l", serde "minimal") ? "Barely anything",
+        High => "high",
+        #[deprecated] Max => "max",
+        #[parse(skip)] Internal => "internal",
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_value_enum() {
+    assert_eq!(
+        Level::value_variants(),
+        &[Level::Low, Level::High, Level::Max]
+    );
+    let low = Level::Low.to_possible_value().unwrap();
+    assert_eq!(low.get_name(), "low");
+    assert_eq!(low.get_name_and_aliases().collect::<Vec<_>>(), ["low", "l"]);
+    assert_eq!(
+        low.get_help().map(ToString::to_string).as_deref(),
+        Some("Barely anything")
+    );
+    assert!(
+        Level::High
+            .to_possible_value()
+            .unwrap()
+            .get_help()
+            .is_none()
+    );
+    assert!(Level::Max.to_possible_value().unwrap().is_hide_set());
+    assert!(Level::Internal.to_possible_value().is_none());
+}
+
+#[test]
+fn test_value_enum_from_str() {
+    assert_eq!(<Level as ValueEnum>::from_str("l", false), Ok(Level::Low));
+    assert_eq!(
+        <Level as ValueEnum>::from_str("HIGH", true),
+        Ok(Level::High)
+    );
+    assert!(<Level as ValueEnum>::from_str("minimal", false).is_err());
+    assert!(<Level as ValueEnum>::from_str("internal", false).is_err());
+}
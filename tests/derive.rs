@@ -0,0 +1,282 @@
+#![cfg(feature = "derive")]
+
+use str_enum::StrEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[str_enum(error_type(DerivedError), parse(case_insensitive))]
+#[repr(u8)]
+pub enum DerivedEnum {
+    #[str("Variant1")]
+    Variant1 = 5,
+    #[str("Variant2", "variant2", parse "v2")]
+    Variant2,
+    #[str("Internal")]
+    #[str_enum(parse(skip))]
+    Internal,
+}
+
+#[test]
+fn test_derive_parse() {
+    assert_eq!(
+        DerivedEnum::try_from_str("Variant1"),
+        Some(DerivedEnum::Variant1)
+    );
+    assert_eq!(
+        DerivedEnum::try_from_str("VARIANT1"),
+        Some(DerivedEnum::Variant1)
+    );
+    assert_eq!(DerivedEnum::try_from_str("v2"), Some(DerivedEnum::Variant2));
+    assert_eq!(DerivedEnum::try_from_str("Internal"), None);
+    assert_eq!(
+        "nonexistent"
+            .parse::<DerivedEnum>()
+            .unwrap_err()
+            .to_string(),
+        "invalid DerivedEnum: expected one of \"Variant1\" or \"Variant2\""
+    );
+}
+
+#[test]
+fn test_derive_matches_macro() {
+    assert_eq!(
+        DerivedEnum::ALL_VALUES,
+        &["Variant1", "Variant2", "Internal"]
+    );
+    assert_eq!(DerivedEnum::Variant2.as_str(), "Variant2");
+    assert_eq!(DerivedEnum::Variant1.into_repr(), 5);
+    assert_eq!(DerivedEnum::Variant2.to_string(), "Variant2");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[str_enum(rename_all = "snake_case", catch_all(MaybeRenamed))]
+pub enum DerivedRenamed {
+    /// Documented
+    HttpServer,
+    #[str("dns", "DNS")]
+    Dns,
+}
+
+#[test]
+fn test_derive_rename_all() {
+    assert_eq!(DerivedRenamed::ALL_VALUES, &["http_server", "dns"]);
+    assert_eq!(
+        DerivedRenamed::try_from_str("DNS"),
+        Some(DerivedRenamed::Dns)
+    );
+}
+
+#[test]
+fn test_derive_documentation() {
+    assert_eq!(
+        DerivedRenamed::HttpServer.documentation(),
+        Some("Documented")
+    );
+    assert_eq!(DerivedRenamed::Dns.documentation(), None);
+}
+
+#[test]
+fn test_derive_catch_all() {
+    assert_eq!(MaybeRenamed::new("dns").known(), Some(&DerivedRenamed::Dns));
+    assert_eq!(MaybeRenamed::new("ftp").as_str(), "ftp");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+pub enum DerivedDescribed {
+    #[str_enum(description = "The first one")]
+    First,
+    Second,
+}
+
+#[test]
+fn test_derive_description() {
+    assert_eq!(DerivedDescribed::First.description(), "The first one");
+    assert_eq!(DerivedDescribed::ALL_DESCRIPTIONS, &["The first one", ""]);
+}
+
+#[cfg(feature = "strum")]
+#[test]
+fn test_derive_properties() {
+    use str_enum::strum::EnumProperty;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+    enum DerivedProperties {
+        #[str_enum(properties(colour = "red", weight = 3))]
+        First,
+        Second,
+    }
+
+    assert_eq!(DerivedProperties::First.get_str("colour"), Some("red"));
+    assert_eq!(DerivedProperties::First.get_int("weight"), Some(3));
+    assert_eq!(DerivedProperties::Second.get_int("weight"), None);
+}
+
+#[test]
+fn test_derive_predicates() {
+    assert!(DerivedRenamed::HttpServer.is_http_server());
+    assert!(!DerivedRenamed::Dns.is_http_server());
+    assert!(DerivedRenamed::Dns.is_dns());
+}
+
+#[test]
+fn test_derive_renamed_predicate() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+    enum DerivedBlank {
+        #[str_enum(predicate = is_blank)]
+        Empty,
+        Full,
+    }
+
+    assert!(DerivedBlank::Empty.is_blank());
+    assert!(!DerivedBlank::Full.is_blank());
+    assert!(DerivedBlank::Full.is_full());
+    assert!(!DerivedBlank::Empty.is_empty());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[repr(u16, align(4))]
+pub enum DerivedTagged {
+    First = 7,
+    Second,
+}
+
+#[test]
+fn test_derive_layout_repr() {
+    assert_eq!(std::mem::align_of::<DerivedTagged>(), 4);
+    assert_eq!(DerivedTagged::Second.into_repr(), 8);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[repr(C)]
+pub enum DerivedC {
+    First,
+    Second,
+}
+
+#[test]
+fn test_derive_c_repr() {
+    assert_eq!(DerivedC::try_from_str("Second"), Some(DerivedC::Second));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[str_enum(skip_impls(Display, Add))]
+pub enum DerivedQuiet {
+    First,
+    Second,
+}
+
+impl std::fmt::Display for DerivedQuiet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}>", self.as_str())
+    }
+}
+
+#[test]
+fn test_derive_skip_impls() {
+    assert_eq!(DerivedQuiet::Second.to_string(), "<Second>");
+}
+
+#[derive(Debug, Clone, Copy, StrEnum)]
+#[str_enum(cmp(str), rename_all = "lowercase")]
+pub enum DerivedSorted {
+    Zulu,
+    Alpha,
+}
+
+#[test]
+fn test_derive_cmp_str() {
+    assert!(DerivedSorted::Alpha < DerivedSorted::Zulu);
+    assert_eq!(DerivedSorted::Zulu, DerivedSorted::Zulu);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[str_enum(enum_map(DerivedCounts))]
+pub enum DerivedCounted {
+    First = 3,
+    Second = 1,
+}
+
+#[test]
+fn test_derive_enum_map() {
+    let mut counts = DerivedCounts::<u8>::default();
+    counts[DerivedCounted::Second] += 1;
+    assert_eq!(counts.into_array(), [0, 1]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[str_enum(enum_set(DerivedFlags), rename_all = "lowercase")]
+pub enum DerivedFlag {
+    Read,
+    Write,
+}
+
+#[test]
+fn test_derive_enum_set() {
+    let flags: DerivedFlags = "write,read".parse().unwrap();
+    assert_eq!(flags, DerivedFlags::all());
+    assert_eq!(flags.to_string(), "read,write");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+pub enum DerivedTarget {
+    Native,
+    #[cfg(any())]
+    Missing,
+}
+
+#[test]
+fn test_derive_cfg_variants() {
+    assert_eq!(DerivedTarget::ALL_VALUES, &["Native"]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+#[str_enum(constant(weight: f32))]
+pub enum DerivedWeighted {
+    #[str_enum(constant = 0.5)]
+    Light,
+    #[str_enum(constant = 2.0)]
+    Heavy,
+}
+
+#[test]
+fn test_derive_constant() {
+    assert_eq!(DerivedWeighted::Heavy.weight(), 2.0);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_derive_http() {
+    use str_enum::http::HeaderValue;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+    #[str_enum(http, error_type(DerivedMethodError))]
+    enum DerivedMethod {
+        #[str("GET")]
+        Get,
+    }
+
+    assert_eq!(HeaderValue::from(DerivedMethod::Get), "GET");
+    assert_eq!(
+        DerivedMethod::try_from(HeaderValue::from_static("GET")),
+        Ok(DerivedMethod::Get)
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, StrEnum)]
+pub enum DerivedScheme {
+    #[str("https")]
+    Https,
+    #[str("http")]
+    #[deprecated(note = "use `DerivedScheme::Https`")]
+    Http,
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_derive_deprecated_variant() {
+    assert!(!DerivedScheme::Https.is_deprecated());
+    assert!(DerivedScheme::Http.is_deprecated());
+    assert_eq!(
+        DerivedScheme::try_from_str("http"),
+        Some(DerivedScheme::Http)
+    );
+}
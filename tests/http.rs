@@ -0,0 +1,64 @@
+#![cfg(feature = "http")]
+
+use str_enum::{Utf8EnumError, http::HeaderValue, str_enum};
+
+str_enum! {
+    #[error_type(ContentEncodingError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[http]
+    pub(crate) enum ContentEncoding {
+        Gzip => "gzip"("x-gzip"),
+        Identity => "identity",
+    }
+}
+
+str_enum! {
+    #[error_type(StreetError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[http]
+    pub(crate) enum Street {
+        Strasse => "Straße",
+    }
+}
+
+// no `#[http]`, so values that can't be header values are fine
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Separator {
+        Newline => "\n",
+        Return => "\r",
+    }
+}
+
+#[test]
+fn test_into_header_value() {
+    assert_eq!(HeaderValue::from(ContentEncoding::Gzip), "gzip");
+    assert_eq!(HeaderValue::from(&ContentEncoding::Identity), "identity");
+
+    let value = HeaderValue::from(Street::Strasse);
+    assert_eq!(value.as_bytes(), "Straße".as_bytes());
+    assert_eq!(Street::try_from(&value), Ok(Street::Strasse));
+}
+
+#[test]
+fn test_try_from_header_value() {
+    assert_eq!(
+        ContentEncoding::try_from(&HeaderValue::from_static("x-gzip")),
+        Ok(ContentEncoding::Gzip)
+    );
+    assert_eq!(
+        ContentEncoding::try_from(HeaderValue::from_static("br")),
+        Err(Utf8EnumError::InvalidVariant(ContentEncodingError))
+    );
+    let opaque = HeaderValue::from_bytes(b"\xffgzip").unwrap();
+    assert!(matches!(
+        ContentEncoding::try_from(&opaque),
+        Err(Utf8EnumError::Utf8(_))
+    ));
+}
+
+#[test]
+fn test_http_is_opt_in() {
+    assert_eq!(Separator::Newline.as_str(), "\n");
+    assert!(HeaderValue::from_str(Separator::Return.as_str()).is_err());
+}
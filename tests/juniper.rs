@@ -0,0 +1,115 @@
+#![cfg(feature = "juniper")]
+
+use str_enum::{
+    juniper::{
+        DefaultScalarValue, EmptyMutation, EmptySubscription, FromInputValue, GraphQLType,
+        InputValue, ParseScalarValue, RootNode, ToInputValue, Variables, graphql_object,
+        graphql_value, parser::ScalarToken,
+    },
+    str_enum,
+};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Colour {
+        Red => "red"("crimson"),
+        LightBlue => "light-blue",
+    }
+}
+
+#[test]
+fn test_from_input_value() {
+    let value = InputValue::<DefaultScalarValue>::scalar("crimson".to_string());
+    assert_eq!(Colour::from_input_value(&value).ok(), Some(Colour::Red));
+
+    let error = Colour::from_input_value(&InputValue::<DefaultScalarValue>::scalar(
+        "green".to_string(),
+    ))
+    .unwrap_err();
+    assert_eq!(
+        error.message(),
+        r#"invalid Colour: expected one of "red" or "light-blue""#
+    );
+    assert!(Colour::from_input_value(&InputValue::<DefaultScalarValue>::scalar(true)).is_err());
+}
+
+#[test]
+fn test_to_input_value() {
+    let value: InputValue<DefaultScalarValue> = Colour::LightBlue.to_input_value();
+    assert_eq!(value, InputValue::scalar("light-blue".to_string()));
+    assert_eq!(
+        <Colour as GraphQLType<DefaultScalarValue>>::name(&()),
+        Some("Colour")
+    );
+}
+
+#[test]
+fn test_parse_literal() {
+    let parsed =
+        <Colour as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::String("red"));
+    assert_eq!(parsed, Ok(DefaultScalarValue::String("red".to_string())));
+    assert!(
+        <Colour as ParseScalarValue<DefaultScalarValue>>::from_str(ScalarToken::Int("1")).is_err()
+    );
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn echo(colour: Colour) -> Colour {
+        colour
+    }
+}
+
+#[test]
+fn test_execute() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let (result, errors) = str_enum::juniper::execute_sync(
+        r#"{ echo(colour: "crimson") }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &(),
+    )
+    .unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(result, graphql_value!({ "echo": "red" }));
+
+    assert!(
+        str_enum::juniper::execute_sync(
+            r#"{ echo(colour: "green") }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_execute_async() {
+    let schema = RootNode::new(
+        Query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+
+    let (result, errors) = str_enum::juniper::execute(
+        r#"{ echo(colour: "light-blue") }"#,
+        None,
+        &schema,
+        &Variables::new(),
+        &(),
+    )
+    .await
+    .unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(result, graphql_value!({ "echo": "light-blue" }));
+}
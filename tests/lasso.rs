@@ -0,0 +1,32 @@
+#![cfg(feature = "lasso")]
+
+use str_enum::{lasso::Rodeo, str_enum};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[lasso(MyEnumKeys)]
+    pub(crate) enum MyEnum {
+        Variant1 => "Variant1"("variant1"),
+        Variant2 => "Variant2",
+    }
+}
+
+#[test]
+fn test_seed_interner() {
+    let mut rodeo = Rodeo::default();
+    let keys = MyEnum::seed_interner(&mut rodeo);
+    assert_eq!(rodeo.len(), MyEnum::NUM_VARIANTS);
+    assert_eq!(rodeo.resolve(&keys.Variant1), "Variant1");
+    assert_eq!(rodeo.resolve(&keys.get(&MyEnum::Variant2)), "Variant2");
+}
+
+#[test]
+fn test_seed_interner_matches_later_interning() {
+    let mut rodeo = Rodeo::default();
+    let keys = MyEnum::seed_interner(&mut rodeo);
+    let key = rodeo.get_or_intern("Variant2");
+    assert_eq!(keys.variant(key), Some(MyEnum::Variant2));
+
+    let other = rodeo.get_or_intern("nonexistent");
+    assert_eq!(keys.variant(other), None);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "log")]
+
+use std::sync::{Mutex, Once};
+
+use str_enum::{log, str_enum};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum MyEnum {
+        Variant1 => "Variant1"("variant1", deprecated "var1"),
+        Variant2 => "Variant2"(deprecated serde "var2"),
+        #[deprecated]
+        Variant3 => "Variant3",
+    }
+}
+
+#[cfg(feature = "phf")]
+str_enum! {
+    #[phf]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum PhfEnum {
+        Variant1 => "Variant1"(deprecated "var1"),
+    }
+}
+
+static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct CaptureLogger;
+
+impl log::Log for CaptureLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record) {
+        MESSAGES.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+fn messages() -> Vec<String> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&CaptureLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+    });
+    MESSAGES.lock().unwrap().clone()
+}
+
+#[test]
+fn test_deprecated_warns_once() {
+    assert!(messages().is_empty());
+
+    assert_eq!(MyEnum::try_from_str("variant1"), Some(MyEnum::Variant1));
+    assert_eq!(MyEnum::try_from_str("Variant1"), Some(MyEnum::Variant1));
+    assert!(messages().is_empty());
+
+    assert_eq!(MyEnum::try_from_str("var1"), Some(MyEnum::Variant1));
+    assert_eq!(MyEnum::try_from_str("var1"), Some(MyEnum::Variant1));
+    assert_eq!(
+        messages(),
+        ["deprecated MyEnum value \"var1\" was parsed, use \"Variant1\" instead"]
+    );
+
+    // scoped to serde, so not accepted (or reported) by try_from_str
+    assert_eq!(MyEnum::try_from_str("var2"), None);
+    assert_eq!(messages().len(), 1);
+
+    assert!(MyEnum::try_from_str("Variant3").is_some());
+    assert!(MyEnum::try_from_str("Variant3").is_some());
+    assert_eq!(
+        messages()[1..],
+        ["deprecated MyEnum value \"Variant3\" was parsed"]
+    );
+
+    #[cfg(feature = "phf")]
+    {
+        assert_eq!(PhfEnum::try_from_str("var1"), Some(PhfEnum::Variant1));
+        assert_eq!(PhfEnum::try_from_str("var1"), Some(PhfEnum::Variant1));
+        assert_eq!(
+            messages()[2..],
+            ["deprecated PhfEnum value \"var1\" was parsed, use \"Variant1\" instead"]
+        );
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "memchr")]
+
+use str_enum::str_enum;
+
+str_enum! {
+    #[error_type(ContentTypeError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum ContentType {
+        FormUrlEncoded => "application/x-www-form-urlencoded"("application/x-www-form"),
+        OctetStream => "application/octet-stream",
+        Json => "application/json",
+    }
+}
+
+#[test]
+fn test_memchr_try_from_str_primary() {
+    for variant in ContentType::ALL_VARIANTS {
+        assert_eq!(ContentType::try_from_str(variant.as_str()), Some(*variant));
+    }
+}
+
+#[test]
+fn test_memchr_try_from_str_alternate() {
+    assert_eq!(
+        ContentType::try_from_str("application/x-www-form"),
+        Some(ContentType::FormUrlEncoded)
+    );
+}
+
+#[test]
+fn test_memchr_try_from_str_invalid() {
+    let invalid_inputs = [
+        "",
+        "application/jso",
+        "application/jsonx",
+        "application/octet-streaM",
+    ];
+    for val in invalid_inputs {
+        assert_eq!(ContentType::try_from_str(val), None, "{val:?}");
+        assert!(val.parse::<ContentType>().is_err());
+    }
+}
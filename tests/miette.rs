@@ -0,0 +1,55 @@
+#![cfg(feature = "miette")]
+
+use str_enum::{
+    miette::{Diagnostic, LabeledSpan},
+    str_enum,
+};
+
+str_enum! {
+    #[error_type(ColourError, capture_input)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Colour {
+        Red => "red",
+        Blue => "blue",
+    }
+}
+
+str_enum! {
+    #[error_type(ShapeError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Shape {
+        Circle => "circle",
+        Square => "square",
+    }
+}
+
+#[test]
+fn test_help() {
+    let error = "gren".parse::<Shape>().unwrap_err();
+    assert_eq!(
+        error.help().unwrap().to_string(),
+        r#"expected one of "circle" or "square""#
+    );
+    assert!(error.source_code().is_none());
+    assert!(error.labels().is_none());
+}
+
+#[test]
+fn test_labels() {
+    let error = "blu".parse::<Colour>().unwrap_err();
+    assert!(error.source_code().is_some());
+    let labels: Vec<LabeledSpan> = error.labels().unwrap().collect();
+    assert_eq!(
+        labels,
+        [LabeledSpan::new(
+            Some(r#"did you mean "blue"?"#.to_string()),
+            0,
+            3
+        )]
+    );
+
+    let error = "purple".parse::<Colour>().unwrap_err();
+    let labels: Vec<LabeledSpan> = error.labels().unwrap().collect();
+    assert_eq!(labels[0].label(), Some("not a valid Colour"));
+    assert_eq!(labels[0].len(), 6);
+}
@@ -0,0 +1,34 @@
+#![no_std]
+
+// the generated code must only need `core` and `alloc` from the invoking crate
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use str_enum::str_enum;
+
+str_enum! {
+    #[error_type(NoStdError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u8)]
+    pub(crate) enum NoStdEnum {
+        Variant1 => "Variant1"("variant1"),
+        Variant2 => "Variant2",
+    }
+}
+
+#[test]
+fn test_no_std_parse() {
+    assert_eq!(
+        NoStdEnum::try_from_str("variant1"),
+        Some(NoStdEnum::Variant1)
+    );
+    assert_eq!("Variant2".parse::<NoStdEnum>(), Ok(NoStdEnum::Variant2));
+    assert!("nonexistent".parse::<NoStdEnum>().is_err());
+}
+
+#[test]
+fn test_no_std_strings() {
+    assert_eq!(NoStdEnum::Variant1.to_string(), "Variant1");
+    assert_eq!(NoStdEnum::Variant2, String::from("Variant2"));
+    assert_eq!(NoStdEnum::Variant2.into_repr(), 1);
+}
@@ -23,11 +23,47 @@ str_enum! {
     }
 }
 
+str_enum! {
+    #[phf]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum PhfSkipEnum {
+        Variant1 => "Variant1",
+        #[parse(skip)]
+        Internal => "Internal"("internal"),
+    }
+}
+
+#[test]
+fn test_phf_parse_skip() {
+    assert_eq!(PhfSkipEnum::try_from_str("Internal"), None);
+    assert_eq!(PhfSkipEnum::try_from_str("internal"), None);
+    assert_eq!(
+        PhfSkipEnum::try_from_str("Variant1"),
+        Some(PhfSkipEnum::Variant1)
+    );
+}
+
 #[test]
 fn test_phf_has_map() {
     assert!(!PhfEnum::PHF_MAP.is_empty())
 }
 
+mod outside {
+    #[test]
+    fn test_phf_map_is_public() {
+        assert_eq!(
+            super::PhfEnum::PHF_MAP.get("Variant1"),
+            Some(&super::PhfEnum::Variant1)
+        );
+        assert_eq!(super::PhfEnum::PHF_MAP.len(), 2);
+        assert_eq!(super::PhfEnum::PHF_MAP.get("variant1"), None);
+        assert_eq!(
+            super::PhfEnum::match_detailed_scoped("variant1", str_enum::AliasScope::Parse),
+            Some((super::PhfEnum::Variant1, "variant1"))
+        );
+    }
+}
+
 #[test]
 fn test_phf_try_from_str_primary() {
     assert_eq!(PhfEnum::try_from_str("Variant1"), Some(PhfEnum::Variant1));
@@ -108,6 +144,77 @@ fn test_phf_and_match_agree_on_invalid() {
     }
 }
 
+str_enum! {
+    #[phf]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum PhfScopedEnum {
+        Variant1 => "Variant1"("variant1", parse "v1", serde "variant_1"),
+    }
+}
+
+#[test]
+fn test_phf_scoped_aliases() {
+    use str_enum::AliasScope;
+
+    assert_eq!(
+        PhfScopedEnum::try_from_str("v1"),
+        Some(PhfScopedEnum::Variant1)
+    );
+    assert_eq!(PhfScopedEnum::try_from_str("variant_1"), None);
+    assert_eq!(
+        PhfScopedEnum::try_from_str_scoped("variant_1", AliasScope::Serde),
+        Some(PhfScopedEnum::Variant1)
+    );
+    assert_eq!(
+        PhfScopedEnum::try_from_str_scoped("v1", AliasScope::Serde),
+        None
+    );
+}
+
+str_enum! {
+    #[phf]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum PhfSharedSpelling {
+        A => "a"(parse "x"),
+        B => "b"(serde "x"),
+    }
+}
+
+#[test]
+fn test_phf_spelling_shared_across_scopes() {
+    use str_enum::AliasScope;
+
+    assert_eq!(
+        PhfSharedSpelling::try_from_str("x"),
+        Some(PhfSharedSpelling::A)
+    );
+    assert_eq!(
+        PhfSharedSpelling::try_from_str_scoped("x", AliasScope::Serde),
+        Some(PhfSharedSpelling::B)
+    );
+    assert_eq!(
+        PhfSharedSpelling::try_from_str("a"),
+        Some(PhfSharedSpelling::A)
+    );
+    assert_eq!(
+        PhfSharedSpelling::try_from_str("b"),
+        Some(PhfSharedSpelling::B)
+    );
+}
+
+#[test]
+fn test_phf_match_detailed() {
+    assert_eq!(
+        PhfEnum::match_detailed("variant1"),
+        Some((PhfEnum::Variant1, "variant1"))
+    );
+    assert_eq!(
+        PhfScopedEnum::match_detailed("v1"),
+        Some((PhfScopedEnum::Variant1, "v1"))
+    );
+    assert_eq!(PhfScopedEnum::match_detailed("variant_1"), None);
+}
+
 #[test]
 fn test_phf_try_from_trait() {
     let v1 = PhfEnum::try_from("Variant1").unwrap();
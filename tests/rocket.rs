@@ -0,0 +1,47 @@
+#![cfg(feature = "rocket")]
+
+use str_enum::{
+    rocket::{
+        form::{FromFormField, ValueField, error::ErrorKind},
+        http::uri::fmt::{Path, UriDisplay},
+        request::FromParam,
+    },
+    str_enum,
+};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Colour {
+        Red => "red"("crimson"),
+        Light => "light blue",
+        #[parse(skip)] Internal => "internal",
+    }
+}
+
+#[test]
+fn test_from_param() {
+    assert_eq!(Colour::from_param("crimson"), Ok(Colour::Red));
+    assert_eq!(Colour::from_param("internal"), Err("internal"));
+}
+
+#[test]
+fn test_from_form_field() {
+    let field = ValueField::parse("colour=crimson");
+    assert_eq!(Colour::from_value(field), Ok(Colour::Red));
+
+    let errors = Colour::from_value(ValueField::parse("colour=internal")).unwrap_err();
+    assert_eq!(errors[0].name.as_ref().unwrap(), "colour");
+    assert_eq!(errors[0].value.as_deref(), Some("internal"));
+    let ErrorKind::InvalidChoice { choices } = &errors[0].kind else {
+        panic!("expected an invalid choice, got {:?}", errors[0].kind);
+    };
+    assert_eq!(choices.as_ref(), ["red", "light blue"]);
+}
+
+#[test]
+fn test_uri_display() {
+    assert_eq!(
+        (&Colour::Light as &dyn UriDisplay<Path>).to_string(),
+        "light%20blue"
+    );
+}
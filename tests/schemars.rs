@@ -0,0 +1,36 @@
+#![cfg(feature = "schemars")]
+
+use serde_json::json;
+use str_enum::{schemars::schema_for, str_enum};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum MyEnum {
+        Variant1 => "Variant1"("variant1"),
+        Variant2 => "Variant2",
+        #[parse(skip)] Internal => "Internal",
+    }
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum OpenEnum {
+        Known => "known",
+        #[parse(fallback)] Unknown => "unknown",
+    }
+}
+
+#[test]
+fn test_json_schema() {
+    let schema = schema_for!(MyEnum);
+    assert_eq!(schema.get("title"), Some(&json!("MyEnum")));
+    assert_eq!(schema.get("type"), Some(&json!("string")));
+    assert_eq!(schema.get("enum"), Some(&json!(["Variant1", "Variant2"])));
+}
+
+#[test]
+fn test_json_schema_fallback() {
+    let schema = schema_for!(OpenEnum);
+    assert_eq!(schema.get("enum"), None);
+    assert_eq!(schema.get("examples"), Some(&json!(["known", "unknown"])));
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "sqlx")]
+
+use sqlx::{Connection, Row, SqliteConnection};
+use str_enum::str_enum;
+
+str_enum! {
+    #[error_type(ColourError, capture_input)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Colour {
+        Red => "red"("crimson"),
+        LightBlue => "light-blue",
+    }
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum Shape {
+        Circle => "circle",
+    }
+}
+
+#[tokio::test]
+async fn test_round_trip() {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE paint (colour TEXT NOT NULL)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO paint (colour) VALUES (?), ('crimson')")
+        .bind(Colour::LightBlue)
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let rows: Vec<(String, Colour)> = sqlx::query_as("SELECT colour, colour FROM paint")
+        .fetch_all(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(
+        rows,
+        [
+            ("light-blue".to_string(), Colour::LightBlue),
+            ("crimson".to_string(), Colour::Red)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_decode_error() {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+    let row = sqlx::query("SELECT 'green'")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    let sqlx::Error::ColumnDecode { source, .. } = row.try_get::<Colour, _>(0).unwrap_err() else {
+        panic!("expected a decode error");
+    };
+    assert_eq!(
+        source.downcast_ref::<ColourError>(),
+        Some(&ColourError("green".to_string()))
+    );
+
+    let row = sqlx::query("SELECT 'square'")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    let sqlx::Error::ColumnDecode { source, .. } = row.try_get::<Shape, _>(0).unwrap_err() else {
+        panic!("expected a decode error");
+    };
+    assert_eq!(
+        source.to_string(),
+        r#"invalid Shape: expected one of "circle""#
+    );
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, hash::DefaultHasher};
 
-use str_enum::str_enum;
+use str_enum::{str_enum, str_enum_impls};
 
 #[cfg(not(feature = "strum"))]
 str_enum! {
@@ -30,6 +30,15 @@ fn test_repr() {
     assert_eq!(MyEnum::Variant1.into_repr(), 5);
 }
 
+#[test]
+fn test_from_repr() {
+    assert_eq!(MyEnum::from_repr(5), Some(MyEnum::Variant1));
+    assert_eq!(MyEnum::from_repr(6), Some(MyEnum::Variant2));
+    assert_eq!(MyEnum::from_repr(0), None);
+    assert_eq!(MyEnum::try_from(6u8).unwrap(), MyEnum::Variant2);
+    assert!(MyEnum::try_from(7u8).is_err());
+}
+
 #[test]
 fn test_from_str_primary() {
     let v1: MyEnum = "Variant1".parse().unwrap();
@@ -79,7 +88,10 @@ fn test_all_values_str() {
 
 #[test]
 fn test_error_expected_str() {
-    assert_eq!(MyError::EXPECTED_STR, "expected one of [Variant1,Variant2]");
+    assert_eq!(
+        MyError::EXPECTED_STR,
+        "invalid MyEnum: expected one of \"Variant1\" or \"Variant2\""
+    );
 }
 
 #[test]
@@ -91,7 +103,10 @@ fn test_display() {
 #[test]
 fn test_error_display() {
     let err = MyError;
-    assert_eq!(format!("{err}"), "expected one of [Variant1,Variant2]");
+    assert_eq!(
+        format!("{err}"),
+        "invalid MyEnum: expected one of \"Variant1\" or \"Variant2\""
+    );
 }
 
 #[test]
@@ -107,6 +122,260 @@ fn test_hashmap_str_lookup() {
     assert_eq!(map.get("variant1"), None);
 }
 
+#[cfg(feature = "conversions")]
+#[test]
+fn test_ref_conversions() {
+    let v1 = &MyEnum::Variant1;
+    assert_eq!(String::from(v1), "Variant1");
+    assert_eq!(Box::<str>::from(v1), "Variant1".into());
+    assert_eq!(std::borrow::Cow::<str>::from(v1), "Variant1");
+    assert_eq!(<&'static str>::from(v1), "Variant1");
+}
+
+#[test]
+fn test_ref_partial_eq() {
+    let v1 = &MyEnum::Variant1;
+    assert_eq!(v1, String::from("Variant1"));
+    assert_eq!(String::from("Variant1"), v1);
+    #[cfg(feature = "std")]
+    assert_eq!(v1, std::path::PathBuf::from("Variant1"));
+    assert_ne!(v1, String::from("Variant2"));
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_from_iterator() {
+    let variants = || [MyEnum::Variant1, MyEnum::Variant2].into_iter();
+    assert_eq!(variants().collect::<String>(), "Variant1Variant2");
+    assert_eq!(
+        variants().collect::<std::ffi::OsString>(),
+        "Variant1Variant2"
+    );
+    assert_eq!(variants().collect::<Vec<u8>>(), b"Variant1Variant2");
+    assert_eq!(
+        variants().collect::<std::path::PathBuf>(),
+        std::path::Path::new("Variant1").join("Variant2")
+    );
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum ScopedEnum {
+        Variant1 => "Variant1"("variant1", parse "v1", serde "variant_1"),
+    }
+}
+
+#[test]
+fn test_scoped_aliases() {
+    assert_eq!(
+        ScopedEnum::try_from_str("variant1"),
+        Some(ScopedEnum::Variant1)
+    );
+    assert_eq!(ScopedEnum::try_from_str("v1"), Some(ScopedEnum::Variant1));
+    assert_eq!(ScopedEnum::try_from_str("variant_1"), None);
+
+    use str_enum::AliasScope;
+    let scoped = |s, scope| ScopedEnum::try_from_str_scoped(s, scope);
+    assert_eq!(scoped("v1", AliasScope::Serde), None);
+    assert_eq!(
+        scoped("variant_1", AliasScope::Serde),
+        Some(ScopedEnum::Variant1)
+    );
+    assert_eq!(
+        scoped("Variant1", AliasScope::Serde),
+        Some(ScopedEnum::Variant1)
+    );
+    assert_eq!(scoped("v1", AliasScope::All), Some(ScopedEnum::Variant1));
+    assert_eq!(
+        scoped("variant_1", AliasScope::All),
+        Some(ScopedEnum::Variant1)
+    );
+}
+
+#[test]
+fn test_match_detailed() {
+    assert_eq!(
+        MyEnum::match_detailed("Variant1"),
+        Some((MyEnum::Variant1, "Variant1"))
+    );
+    assert_eq!(
+        MyEnum::match_detailed("variant1"),
+        Some((MyEnum::Variant1, "variant1"))
+    );
+    assert_eq!(MyEnum::match_detailed("nonexistent"), None);
+    assert_eq!(
+        ScopedEnum::match_detailed("v1"),
+        Some((ScopedEnum::Variant1, "v1"))
+    );
+    assert_eq!(ScopedEnum::match_detailed("variant_1"), None);
+}
+
+#[test]
+fn test_fuzz_check() {
+    let inputs: [&[u8]; 6] = [b"", b"Variant1", b"variant1", b"v1", b"Variant3", b"\xff"];
+    for data in inputs {
+        str_enum::fuzz_check!(MyEnum, data);
+        str_enum::fuzz_check!(ScopedEnum, data);
+    }
+}
+
+str_enum! {
+    #[error_type(SkipError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[str_type(SkipEnumStr)]
+    enum SkipEnum {
+        Variant1 => "Variant1",
+        #[parse(skip)]
+        Internal => "Internal"("internal"),
+        Variant2 => "Variant2",
+    }
+}
+
+#[test]
+fn test_parse_skip() {
+    assert_eq!(SkipEnum::Internal.as_str(), "Internal");
+    assert!(SkipEnum::Internal.parse_skipped());
+    assert!(!SkipEnum::Variant1.parse_skipped());
+    assert_eq!(SkipEnum::try_from_str("Internal"), None);
+    assert_eq!(SkipEnum::try_from_str("internal"), None);
+    assert_eq!(SkipEnum::match_detailed("Internal"), None);
+    assert_eq!(SkipEnum::try_from_str("Variant2"), Some(SkipEnum::Variant2));
+    assert_eq!(SkipEnum::ALL_VALUES, &["Variant1", "Internal", "Variant2"]);
+    assert_eq!(
+        "Internal".parse::<SkipEnum>().unwrap_err().to_string(),
+        "invalid SkipEnum: expected one of \"Variant1\" or \"Variant2\""
+    );
+    assert_eq!(
+        SkipEnumStr::from(SkipEnum::Internal).to_variant(),
+        SkipEnum::Internal
+    );
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[str_type(StrTypeEnumStr)]
+    enum StrTypeEnum {
+        Variant1 => "Variant1"("variant1"),
+        Variant2 => "Variant2",
+    }
+}
+
+str_enum! {
+    #[error_type(CaseInsensitiveError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[parse(case_insensitive)]
+    enum CaseInsensitiveEnum {
+        Value0 => "Value0"(serde "value_0"),
+        Value1 => "value1",
+    }
+}
+
+#[test]
+fn test_case_insensitive() {
+    assert_eq!(
+        CaseInsensitiveEnum::try_from_str("VALUE0"),
+        Some(CaseInsensitiveEnum::Value0)
+    );
+    assert_eq!(
+        "vAlUe1".parse::<CaseInsensitiveEnum>().unwrap(),
+        CaseInsensitiveEnum::Value1
+    );
+    assert_eq!(
+        CaseInsensitiveEnum::try_from("value0").unwrap(),
+        CaseInsensitiveEnum::Value0
+    );
+    assert_eq!(
+        CaseInsensitiveEnum::match_detailed("VALUE1"),
+        Some((CaseInsensitiveEnum::Value1, "value1"))
+    );
+    assert_eq!(CaseInsensitiveEnum::try_from_str("VALUE_0"), None);
+    assert_eq!(CaseInsensitiveEnum::try_from_str("valu"), None);
+}
+
+#[test]
+fn test_try_from_str_by() {
+    assert_eq!(
+        MyEnum::try_from_str_by("VARIANT2", |s, candidate| s.eq_ignore_ascii_case(candidate)),
+        Some(MyEnum::Variant2)
+    );
+    assert_eq!(
+        MyEnum::try_from_str_by("variant1", |s, candidate| s == candidate),
+        Some(MyEnum::Variant1)
+    );
+    assert_eq!(
+        ScopedEnum::try_from_str_by("variant_1", |s, candidate| s == candidate),
+        None
+    );
+    assert_eq!(MyEnum::try_from_str_by("Variant1", |_, _| false), None);
+}
+
+#[test]
+fn test_idl_snippets() {
+    assert_eq!(
+        MyEnum::GRAPHQL_SDL,
+        "enum MyEnum {\n  Variant1 # \"Variant1\"\n  Variant2 # \"Variant2\"\n}\n"
+    );
+    assert_eq!(
+        RenamedEnum::PROTOBUF_ENUM,
+        Some(
+            "enum RenamedEnum {\n  HttpServer = 0; // \"http-server\"\n  Proxy = 1; // \"proxy\"\n  Dns = 2; // \"DNS\"\n}\n"
+        )
+    );
+    assert_eq!(
+        ScreamingEnum::GRAPHQL_SDL,
+        "enum ScreamingEnum {\n  HttpServer # \"HTTP_SERVER\"\n  Type # \"TYPE\"\n}\n"
+    );
+}
+
+#[test]
+fn test_protobuf_enum_needs_proto3_discriminants() {
+    str_enum! {
+        #[derive(Clone, Copy)]
+        #[repr(u64)]
+        enum Wide {
+            Zero = 0 => "zero",
+            Max = 18446744073709551615 => "max",
+        }
+    }
+
+    // the first discriminant of `MyEnum` is 5, not 0
+    assert_eq!(MyEnum::PROTOBUF_ENUM, None);
+    assert_eq!(Wide::PROTOBUF_ENUM, None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_parse_lines() {
+    let input = "Variant1\n\n  variant1  \r\nnonexistent\nVariant2";
+    let parsed: Vec<_> = MyEnum::parse_lines(std::io::Cursor::new(input)).collect();
+    assert_eq!(parsed.len(), 4);
+    assert_eq!(*parsed[0].as_ref().unwrap(), MyEnum::Variant1);
+    assert_eq!(*parsed[1].as_ref().unwrap(), MyEnum::Variant1);
+    let err = parsed[2].as_ref().unwrap_err();
+    assert_eq!(err.line(), 4);
+    assert_eq!(err.to_string(), "line 4: invalid value \"nonexistent\"");
+    assert_eq!(*parsed[3].as_ref().unwrap(), MyEnum::Variant2);
+}
+
+#[test]
+fn test_str_type() {
+    let s = StrTypeEnumStr::new("variant1").unwrap();
+    assert_eq!(s.as_str(), "Variant1");
+    assert_eq!(s.to_variant(), StrTypeEnum::Variant1);
+    assert_eq!(StrTypeEnumStr::new("nonexistent"), None);
+
+    let s = StrTypeEnumStr::from(StrTypeEnum::Variant2);
+    assert_eq!(s, "Variant2");
+    assert_eq!(s, StrTypeEnum::Variant2);
+    assert_eq!(s.len(), 8);
+    assert_eq!(StrTypeEnum::from(s), StrTypeEnum::Variant2);
+    assert_eq!(<&'static str>::from(s), "Variant2");
+
+    const VARIANT: StrTypeEnum = StrTypeEnumStr::from_variant(&StrTypeEnum::Variant1).to_variant();
+    assert_eq!(VARIANT, StrTypeEnum::Variant1);
+    assert_eq!(hash_of(&s), hash_of(&"Variant2"));
+}
+
 fn hash_of<T: std::hash::Hash>(t: &T) -> u64 {
     let mut hasher = DefaultHasher::new();
     t.hash(&mut hasher);
@@ -120,7 +389,7 @@ fn test_hash_different_variants_differ() {
 
 #[cfg(feature = "serde")]
 mod serde {
-    use crate::MyEnum;
+    use crate::{MyEnum, MyError};
 
     #[test]
     fn test_serialize() {
@@ -150,6 +419,139 @@ mod serde {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_serialize_utf8_enum_error() {
+        let err = MyEnum::try_from(b"nope".as_slice()).unwrap_err();
+        assert_eq!(
+            serde_json::to_string(&err).unwrap(),
+            r#"{"InvalidVariant":{"expected":["Variant1","Variant2"]}}"#
+        );
+        let err = MyEnum::try_from(b"\xff".as_slice()).unwrap_err();
+        assert_eq!(
+            serde_json::to_string(&err).unwrap(),
+            r#"{"Utf8":"invalid utf-8 sequence of 1 bytes from index 0"}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_captured_input() {
+        let err = "nope".parse::<crate::Capturing>().unwrap_err();
+        assert_eq!(
+            serde_json::to_string(&err).unwrap(),
+            r#"{"expected":["first","second"],"found":"nope"}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_fallback() {
+        let unknown: crate::Shape = serde_json::from_str("\"hexagon\"").unwrap();
+        assert_eq!(unknown, crate::Shape::Unknown);
+    }
+
+    #[test]
+    fn test_catch_all_round_trip() {
+        use crate::{Colour, MaybeColour};
+
+        let known: MaybeColour = serde_json::from_str("\"scarlet\"").unwrap();
+        assert_eq!(known.known(), Some(&Colour::Red));
+        assert_eq!(serde_json::to_string(&known).unwrap(), "\"red\"");
+
+        let other: MaybeColour = serde_json::from_str("\"mauve\"").unwrap();
+        assert_eq!(other, MaybeColour::Other("mauve".into()));
+        assert_eq!(serde_json::to_string(&other).unwrap(), "\"mauve\"");
+    }
+
+    #[test]
+    fn test_into_deserializer() {
+        use serde::Deserialize;
+        use serde::de::{IntoDeserializer, value::Error};
+
+        let variant: Result<MyEnum, Error> =
+            MyEnum::deserialize(MyEnum::Variant2.into_deserializer());
+        assert_eq!(variant, Ok(MyEnum::Variant2));
+        let borrowed: Result<&str, Error> =
+            <&str>::deserialize(MyEnum::Variant1.into_deserializer());
+        assert_eq!(borrowed, Ok("Variant1"));
+    }
+
+    #[test]
+    fn test_catch_all_tokens() {
+        use crate::{Colour, MaybeColour};
+        use serde_test::{Token, assert_de_tokens, assert_de_tokens_error};
+
+        assert_de_tokens(
+            &MaybeColour::Known(Colour::Red),
+            &[Token::BorrowedStr("scarlet")],
+        );
+        assert_de_tokens(
+            &MaybeColour::Other("mauve".into()),
+            &[Token::Bytes(b"mauve")],
+        );
+        assert_de_tokens_error::<MaybeColour>(
+            &[Token::Bytes(b"\xff")],
+            "invalid value: byte array, expected a string",
+        );
+        assert_de_tokens_error::<MaybeColour>(
+            &[Token::U8(1)],
+            "invalid type: integer `1`, expected a string",
+        );
+    }
+
+    #[test]
+    fn test_enum_map_round_trip() {
+        use crate::{Planet, PlanetMap};
+
+        let moons = PlanetMap::from_fn(|planet| match planet {
+            Planet::Earth => 1,
+            Planet::Mars => 2,
+            Planet::Venus => 0,
+        });
+        let json = serde_json::to_string(&moons).unwrap();
+        assert_eq!(json, r#"{"venus":0,"earth":1,"mars":2}"#);
+        assert_eq!(serde_json::from_str::<PlanetMap<u8>>(&json).unwrap(), moons);
+
+        let partial: PlanetMap<u8> = serde_json::from_str(r#"{"mars":2}"#).unwrap();
+        assert_eq!(partial[Planet::Mars], 2);
+        assert_eq!(partial[Planet::Earth], 0);
+        assert!(serde_json::from_str::<PlanetMap<u8>>(r#"{"mars":2,"mars":3}"#).is_err());
+        assert!(serde_json::from_str::<PlanetMap<u8>>(r#"{"pluto":0}"#).is_err());
+    }
+
+    #[test]
+    fn test_enum_set_round_trip() {
+        use crate::{Planet, PlanetSet};
+
+        let set = PlanetSet::from_iter([Planet::Mars, Planet::Venus]);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#"["venus","mars"]"#);
+        assert_eq!(serde_json::from_str::<PlanetSet>(&json).unwrap(), set);
+        assert!(serde_json::from_str::<PlanetSet>(r#"["pluto"]"#).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_bytes() {
+        use str_enum::serde::{
+            Deserialize,
+            de::value::{BytesDeserializer, Error},
+        };
+
+        let v1 = MyEnum::deserialize(BytesDeserializer::<Error>::new(b"variant1")).unwrap();
+        assert_eq!(v1, MyEnum::Variant1);
+        assert!(MyEnum::deserialize(BytesDeserializer::<Error>::new(b"nonexistent")).is_err());
+        assert!(MyEnum::deserialize(BytesDeserializer::<Error>::new(b"\xff")).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_scoped_aliases() {
+        use crate::ScopedEnum;
+
+        let v1: ScopedEnum = serde_json::from_str("\"variant_1\"").unwrap();
+        assert_eq!(v1, ScopedEnum::Variant1);
+        let v1: ScopedEnum = serde_json::from_str("\"variant1\"").unwrap();
+        assert_eq!(v1, ScopedEnum::Variant1);
+        assert!(serde_json::from_str::<ScopedEnum>("\"v1\"").is_err());
+    }
+
     #[test]
     fn test_serde_roundtrip() {
         for variant in MyEnum::ALL_VARIANTS {
@@ -159,9 +561,257 @@ mod serde {
         }
     }
 
+    #[test]
+    fn test_serialize_error() {
+        assert_eq!(
+            serde_json::to_string(&MyError).unwrap(),
+            r#"{"expected":["Variant1","Variant2"]}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_skipped() {
+        use crate::{SkipEnum, SkipError};
+
+        assert!(serde_json::from_str::<SkipEnum>("\"Internal\"").is_err());
+        assert_eq!(
+            serde_json::from_str::<SkipEnum>("\"Variant1\"").unwrap(),
+            SkipEnum::Variant1
+        );
+        assert_eq!(
+            serde_json::to_string(&SkipEnum::Internal).unwrap(),
+            "\"Internal\""
+        );
+        assert_eq!(
+            serde_json::to_string(&SkipError).unwrap(),
+            r#"{"expected":["Variant1","Variant2"]}"#
+        );
+    }
+
+    #[test]
+    fn test_serde_visitor() {
+        use serde::de::{Deserializer, Error, IntoDeserializer, value};
+
+        struct Wrapper(MyEnum);
+
+        impl<'de> serde::Deserialize<'de> for Wrapper {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer
+                    .deserialize_str(MyEnum::serde_visitor())
+                    .map(Wrapper)
+            }
+        }
+
+        let wrapped: Wrapper = serde_json::from_str("\"variant1\"").unwrap();
+        assert_eq!(wrapped.0, MyEnum::Variant1);
+
+        let deserializer: value::StrDeserializer<'_, value::Error> =
+            "nonexistent".into_deserializer();
+        let err = deserializer
+            .deserialize_str(MyEnum::serde_visitor())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            value::Error::custom(
+                "invalid value: string \"nonexistent\", expected one of \"Variant1\" or \"Variant2\""
+            )
+        );
+    }
+
     #[test]
     fn test_serde_expected_str() {
-        assert_eq!(MyEnum::SERDE_EXPECTED_STR, "one of [Variant1,Variant2]");
+        assert_eq!(
+            MyEnum::SERDE_EXPECTED_STR,
+            "one of \"Variant1\" or \"Variant2\""
+        );
+    }
+
+    str_enum::str_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(u8)]
+        #[serde(compact)]
+        enum CompactEnum {
+            Variant1 = 5 => "Variant1"("variant1"),
+            Variant2 => "Variant2",
+        }
+    }
+
+    str_enum::str_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[serde(compact)]
+        enum CompactNoReprEnum {
+            Variant1 => "Variant1",
+            Variant2 => "Variant2",
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    str_enum::str_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[serde(compact)]
+        #[allow(clippy::enum_clike_unportable_variant)]
+        enum CompactWideEnum {
+            Zero = 0 => "Zero",
+            Wide = 4294967296 => "Wide",
+            Negative = -1 => "Negative",
+        }
+    }
+
+    str_enum::str_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(u8)]
+        #[serde(compact_index)]
+        enum CompactIndexEnum {
+            Variant1 = 5 => "Variant1",
+            #[parse(skip)]
+            Internal = 9 => "Internal",
+            Variant2 = 200 => "Variant2",
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_serde_compact_wide_discriminants() {
+        use serde_test::{Configure, Token, assert_tokens};
+
+        assert_tokens(&CompactWideEnum::Zero.compact(), &[Token::I64(0)]);
+        assert_tokens(&CompactWideEnum::Wide.compact(), &[Token::I64(4294967296)]);
+        assert_tokens(&CompactWideEnum::Negative.compact(), &[Token::I64(-1)]);
+    }
+
+    #[test]
+    fn test_serde_compact() {
+        use serde_test::{Configure, Token, assert_de_tokens_error, assert_tokens};
+
+        assert_tokens(&CompactEnum::Variant1.readable(), &[Token::Str("Variant1")]);
+        assert_tokens(&CompactEnum::Variant1.compact(), &[Token::U8(5)]);
+        assert_tokens(&CompactEnum::Variant2.compact(), &[Token::U8(6)]);
+        assert_tokens(&CompactNoReprEnum::Variant2.compact(), &[Token::I64(1)]);
+        assert_de_tokens_error::<serde_test::Compact<CompactEnum>>(
+            &[Token::U8(0)],
+            "invalid value: unknown discriminant, expected a valid discriminant",
+        );
+
+        assert_tokens(
+            &CompactIndexEnum::Variant2.readable(),
+            &[Token::Str("Variant2")],
+        );
+        assert_tokens(&CompactIndexEnum::Variant2.compact(), &[Token::U32(2)]);
+        assert_de_tokens_error::<serde_test::Compact<CompactIndexEnum>>(
+            &[Token::U32(1)],
+            "invalid value: integer `1`, expected a valid variant index",
+        );
+    }
+
+    str_enum::str_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u16)]
+        #[serde(integers)]
+        enum IntegerEnum {
+            Variant1 = 404 => "Variant1",
+            Variant2 => "Variant2",
+            #[parse(skip)]
+            Internal => "Internal",
+        }
+    }
+
+    #[test]
+    fn test_serde_integers() {
+        use serde_json::{from_value, json, to_value};
+
+        assert_eq!(
+            from_value::<IntegerEnum>(json!("Variant2")).unwrap(),
+            IntegerEnum::Variant2
+        );
+        assert_eq!(
+            from_value::<IntegerEnum>(json!(405)).unwrap(),
+            IntegerEnum::Variant2
+        );
+        assert_eq!(
+            from_value::<IntegerEnum>(json!(406))
+                .unwrap_err()
+                .to_string(),
+            "invalid value: integer `406`, expected a valid discriminant"
+        );
+        assert!(from_value::<IntegerEnum>(json!(-1)).is_err());
+        assert!(from_value::<MyEnum>(json!(5)).is_err());
+        assert_eq!(to_value(IntegerEnum::Variant1).unwrap(), json!("Variant1"));
+    }
+
+    str_enum::str_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[serde_with(with_enum)]
+        enum WithEnum {
+            Variant1 => "Variant1"("variant1"),
+            Variant2 => "Variant2",
+        }
+    }
+
+    #[test]
+    fn test_serde_with_option() {
+        use serde_json::{json, value::Serializer};
+
+        assert_eq!(
+            with_enum::option_empty_as_none::deserialize(json!("")).unwrap(),
+            None
+        );
+        assert_eq!(
+            with_enum::option_empty_as_none::deserialize(json!("variant1")).unwrap(),
+            Some(WithEnum::Variant1)
+        );
+        assert!(with_enum::option_empty_as_none::deserialize(json!("nonexistent")).is_err());
+        assert_eq!(
+            with_enum::option_lenient::deserialize(json!("nonexistent")).unwrap(),
+            None
+        );
+        assert_eq!(
+            with_enum::option_lenient::deserialize(json!("Variant2")).unwrap(),
+            Some(WithEnum::Variant2)
+        );
+
+        assert_eq!(
+            with_enum::option_empty_as_none::serialize(&None, Serializer).unwrap(),
+            json!("")
+        );
+        assert_eq!(
+            with_enum::option_lenient::serialize(&Some(WithEnum::Variant1), Serializer).unwrap(),
+            json!("Variant1")
+        );
+    }
+
+    #[test]
+    fn test_serde_with_comma_separated() {
+        use serde_json::{json, value::Serializer};
+
+        assert_eq!(
+            with_enum::comma_separated::deserialize(json!("Variant2, variant1")).unwrap(),
+            vec![WithEnum::Variant2, WithEnum::Variant1]
+        );
+        assert_eq!(
+            with_enum::comma_separated::deserialize(json!("")).unwrap(),
+            Vec::<WithEnum>::new()
+        );
+        assert!(with_enum::comma_separated::deserialize(json!("Variant1,nonexistent")).is_err());
+        assert_eq!(
+            with_enum::comma_separated_lenient::deserialize(json!("Variant1,nonexistent")).unwrap(),
+            vec![WithEnum::Variant1]
+        );
+
+        assert_eq!(
+            with_enum::comma_separated::serialize(
+                &[WithEnum::Variant1, WithEnum::Variant2],
+                Serializer
+            )
+            .unwrap(),
+            json!("Variant1,Variant2")
+        );
+    }
+
+    #[test]
+    fn test_serde_not_compact() {
+        use serde_test::{Configure, Token, assert_tokens};
+
+        assert_tokens(&MyEnum::Variant1.compact(), &[Token::Str("Variant1")]);
     }
 }
 
@@ -177,6 +827,24 @@ mod strum {
         assert_eq!(MyEnum::COUNT, 2);
     }
 
+    #[test]
+    fn test_enum_message() {
+        use crate::Protocol;
+        use str_enum::strum::EnumMessage;
+
+        assert_eq!(Protocol::V0.get_message(), Some("First protocol version"));
+        assert_eq!(
+            Protocol::V1.get_detailed_message(),
+            Some("Second protocol version")
+        );
+        assert_eq!(Protocol::Latest.get_message(), None);
+        assert_eq!(Protocol::V0.get_documentation(), None);
+        assert_eq!(
+            MyEnum::Variant1.get_serializations(),
+            &["Variant1", "variant1"]
+        );
+    }
+
     #[test]
     fn test_into_enum_iterator() {
         let variants: Vec<MyEnum> = <MyEnum as strum::IntoEnumIterator>::iter().collect();
@@ -238,4 +906,1259 @@ mod strum {
         use str_enum::strum::IntoDiscriminant;
         assert_eq!(MyEnum::Variant1.discriminant(), 5u8);
     }
+
+    #[test]
+    fn test_into_static_str() {
+        fn static_str<T: Into<&'static str>>(value: T) -> &'static str {
+            value.into()
+        }
+
+        fn as_ref_str<T: AsRef<str>>(value: &T) -> &str {
+            value.as_ref()
+        }
+
+        assert_eq!(static_str(MyEnum::Variant2), "Variant2");
+        let values: Vec<&str> = MyEnum::ALL_VARIANTS.iter().map(static_str).collect();
+        assert_eq!(values, ["Variant1", "Variant2"]);
+        assert_eq!(as_ref_str(&MyEnum::Variant1), "Variant1");
+    }
+
+    str_enum::str_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[skip_impls(From)]
+        enum Unconverted {
+            Only => "only",
+        }
+    }
+
+    #[test]
+    fn test_into_static_str_without_from() {
+        fn static_str<T: Into<&'static str>>(value: T) -> &'static str {
+            value.into()
+        }
+
+        assert_eq!(static_str(Unconverted::Only), "only");
+        let values: Vec<&str> = Unconverted::ALL_VARIANTS.iter().map(static_str).collect();
+        assert_eq!(values, ["only"]);
+    }
+}
+
+str_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum BaseTokens {
+        Open => "open"("OPEN"),
+        Close => "close",
+    }
+}
+
+str_enum! {
+    #[include(BaseTokens)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum ExtendedTokens {
+        Reset => "reset",
+    }
+}
+
+#[test]
+fn test_include() {
+    assert_eq!(ExtendedTokens::ALL_VALUES, &["open", "close", "reset"]);
+    assert_eq!(
+        ExtendedTokens::try_from_str("OPEN"),
+        Some(ExtendedTokens::Open)
+    );
+    assert_eq!(
+        ExtendedTokens::from(BaseTokens::Close),
+        ExtendedTokens::Close
+    );
+    assert_eq!(
+        BaseTokens::try_from(ExtendedTokens::Open),
+        Ok(BaseTokens::Open)
+    );
+    assert_eq!(
+        BaseTokens::try_from(ExtendedTokens::Reset),
+        Err(ExtendedTokens::Reset)
+    );
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[rename_all = "kebab-case"]
+    enum RenamedEnum {
+        HttpServer("server"),
+        Proxy,
+        Dns => "DNS",
+    }
+}
+
+#[test]
+fn test_rename_all() {
+    assert_eq!(RenamedEnum::ALL_VALUES, &["http-server", "proxy", "DNS"]);
+    assert_eq!(
+        RenamedEnum::try_from_str("http-server"),
+        Some(RenamedEnum::HttpServer)
+    );
+    assert_eq!(
+        RenamedEnum::try_from_str("server"),
+        Some(RenamedEnum::HttpServer)
+    );
+    assert_eq!(RenamedEnum::try_from_str("HttpServer"), None);
+    assert_eq!(RenamedEnum::Proxy.as_str(), "proxy");
+    assert_eq!(
+        RenamedEnum::GRAPHQL_SDL,
+        "enum RenamedEnum {\n  HttpServer # \"http-server\"\n  Proxy # \"proxy\"\n  Dns # \"DNS\"\n}\n"
+    );
+}
+
+str_enum! {
+    #[derive(Clone)]
+    #[rename_all = "SCREAMING_SNAKE_CASE"]
+    enum ScreamingEnum {
+        HttpServer,
+        r#Type,
+    }
+}
+
+str_enum! {
+    #[derive(Clone)]
+    #[rename_all = "camelCase"]
+    enum CamelEnum {
+        HttpServer,
+    }
+}
+
+#[test]
+fn test_rename_all_rules() {
+    assert_eq!(ScreamingEnum::ALL_VALUES, &["HTTP_SERVER", "TYPE"]);
+    assert_eq!(CamelEnum::ALL_VALUES, &["httpServer"]);
+}
+
+str_enum! {
+    #[error_type(ImplicitError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum ImplicitEnum {
+        Alpha,
+        Beta("beta"),
+        Gamma = 7,
+        Delta => "delta",
+    }
+}
+
+#[test]
+fn test_implicit_values() {
+    assert_eq!(
+        ImplicitEnum::ALL_VALUES,
+        &["Alpha", "Beta", "Gamma", "delta"]
+    );
+    assert_eq!(ImplicitEnum::try_from_str("beta"), Some(ImplicitEnum::Beta));
+    assert_eq!(
+        "Gamma".parse::<ImplicitEnum>().unwrap(),
+        ImplicitEnum::Gamma
+    );
+    assert_eq!(ImplicitEnum::Gamma as u8, 7);
+}
+
+str_enum! {
+    /// An enum with doc comments
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum DocumentedEnum {
+        /// The first variant
+        First => "first",
+        /// The second variant,
+        /// over two lines
+        #[parse(skip)]
+        Second => "second",
+        Third,
+    }
+}
+
+#[test]
+fn test_documentation() {
+    assert_eq!(
+        DocumentedEnum::First.documentation(),
+        Some("The first variant")
+    );
+    assert_eq!(
+        DocumentedEnum::Second.documentation(),
+        Some("The second variant,\n over two lines")
+    );
+    assert_eq!(DocumentedEnum::Third.documentation(), None);
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[non_exhaustive]
+    #[must_use]
+    #[allow(clippy::upper_case_acronyms)]
+    #[cfg_attr(test, derive(PartialOrd))]
+    pub enum AttributedEnum {
+        HTTP => "http",
+        Ftp => "ftp",
+    }
+}
+
+#[test]
+fn test_attribute_passthrough() {
+    assert!(AttributedEnum::HTTP < AttributedEnum::Ftp);
+    assert_eq!(
+        AttributedEnum::try_from_str("ftp"),
+        Some(AttributedEnum::Ftp)
+    );
+}
+
+str_enum! {
+    #[repr(u8)]
+    #[derive(Debug)]
+    #[parse(case_insensitive)]
+    /// Attributes can come in any order
+    #[error_type(ReorderedError)]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum ReorderedEnum {
+        First => "first",
+    }
+}
+
+str_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[include(BaseTokens)]
+    enum ReorderedTokens {
+        Reset => "reset",
+    }
+}
+
+#[test]
+fn test_attributes_any_order() {
+    assert_eq!(
+        "FIRST".parse::<ReorderedEnum>().unwrap(),
+        ReorderedEnum::First
+    );
+    assert_eq!(ReorderedEnum::First as u8, 0);
+    assert_eq!(
+        ReorderedTokens::try_from_str("close"),
+        Some(ReorderedTokens::Close)
+    );
+}
+
+str_enum! {
+    #[derive(core::fmt::Debug, ::core::clone::Clone, std::marker::Copy, PartialEq, Eq)]
+    enum PathDerivedEnum {
+        First => "first",
+    }
+}
+
+#[test]
+fn test_path_derives() {
+    let first = PathDerivedEnum::First;
+    let copied = first;
+    assert_eq!(format!("{first:?}"), "First");
+    assert_eq!(first.clone(), copied);
+}
+
+mod foreign {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u8)]
+    pub enum ForeignEnum {
+        Alpha = 1,
+        Beta = 2,
+    }
+}
+
+use foreign::ForeignEnum;
+
+str_enum_impls! {
+    #[parse(case_insensitive)]
+    #[repr(u8)]
+    pub enum ForeignEnum {
+        Alpha => "alpha",
+        Beta("b"),
+    }
+}
+
+#[test]
+fn test_impls_for_existing_enum() {
+    assert_eq!(ForeignEnum::ALL_VALUES, &["alpha", "Beta"]);
+    assert_eq!(ForeignEnum::try_from_str("ALPHA"), Some(ForeignEnum::Alpha));
+    assert_eq!(ForeignEnum::try_from_str("b"), Some(ForeignEnum::Beta));
+    assert_eq!(u8::from(ForeignEnum::Beta), 2);
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[catch_all(MaybeColour)]
+    pub(crate) enum Colour {
+        Red => "red"("scarlet"),
+        Green => "green",
+    }
+}
+
+#[test]
+fn test_catch_all() {
+    let red = MaybeColour::new("scarlet");
+    assert_eq!(red.known(), Some(&Colour::Red));
+    assert_eq!(red.as_str(), "red");
+    assert_eq!(format!("{red:?}"), "Known(Red)");
+
+    let other: MaybeColour = "mauve".parse().unwrap();
+    assert_eq!(other.known(), None);
+    assert_eq!(other, "mauve");
+    assert_eq!(other.to_string(), "mauve");
+    assert_eq!(format!("{other:?}"), "Other(\"mauve\")");
+
+    assert_eq!(MaybeColour::from(String::from("green")), Colour::Green);
+    assert_eq!(MaybeColour::from(Colour::Green), MaybeColour::new("green"));
+    assert_eq!(other.clone(), other);
+}
+
+str_enum! {
+    #[error_type(ShapeError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Shape {
+        Circle => "circle",
+        Square => "square",
+        #[parse(fallback)]
+        Unknown => "unknown",
+    }
+}
+
+#[test]
+fn test_fallback() {
+    assert_eq!(Shape::fallback(), Some(Shape::Unknown));
+    assert_eq!(MyEnum::fallback(), None);
+    assert_eq!(Shape::try_from_str("square"), Some(Shape::Square));
+    assert_eq!(Shape::try_from_str("hexagon"), Some(Shape::Unknown));
+    assert_eq!("hexagon".parse::<Shape>().unwrap(), Shape::Unknown);
+    assert_eq!(Shape::match_detailed("hexagon"), None);
+    assert!(!Shape::Unknown.parse_skipped());
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Protocol {
+        V0 => "v0" ? "First protocol version",
+        V1 => "v1"("1") ? "Second protocol version",
+        Latest,
+    }
+}
+
+#[test]
+fn test_description() {
+    assert_eq!(Protocol::V0.description(), "First protocol version");
+    assert_eq!(Protocol::Latest.description(), "");
+    assert_eq!(
+        Protocol::ALL_DESCRIPTIONS,
+        &["First protocol version", "Second protocol version", ""]
+    );
+    assert_eq!(Protocol::try_from_str("1"), Some(Protocol::V1));
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Fruit {
+        Apple => "apple" { colour: "red", weight: 180, seeds: true },
+        Banana => "banana"("plantain") { colour: "yellow", weight: -1 } ? "Long and curved",
+        #[predicate(is_grape)]
+        Grape,
+    }
+}
+
+#[cfg(feature = "strum")]
+#[test]
+fn test_enum_property() {
+    use str_enum::strum::EnumProperty;
+
+    assert_eq!(Fruit::Apple.get_str("colour"), Some("red"));
+    assert_eq!(Fruit::Apple.get_int("weight"), Some(180));
+    assert_eq!(Fruit::Apple.get_bool("seeds"), Some(true));
+    assert_eq!(Fruit::Apple.get_int("colour"), None);
+    assert_eq!(Fruit::Banana.get_int("weight"), Some(-1));
+    assert_eq!(Fruit::Banana.get_bool("seeds"), None);
+    assert_eq!(Fruit::Grape.get_str("colour"), None);
+    assert_eq!(Fruit::Banana.description(), "Long and curved");
+    assert_eq!(Fruit::try_from_str("plantain"), Some(Fruit::Banana));
+}
+
+#[test]
+fn test_is() {
+    const { assert!(Fruit::Apple.is(&Fruit::Apple)) };
+    assert!(!Fruit::Apple.is(&Fruit::Grape));
+    assert!(
+        [Fruit::Apple, Fruit::Grape]
+            .iter()
+            .any(|fruit| fruit.is(&Fruit::Grape))
+    );
+}
+
+#[test]
+fn test_predicate() {
+    const { assert!(Fruit::Grape.is_grape()) };
+    assert!(!Fruit::Apple.is_grape());
+}
+
+#[test]
+fn test_try_from_str_const() {
+    const VARIANT1: Option<MyEnum> = MyEnum::try_from_str_const("variant1");
+    assert_eq!(VARIANT1, Some(MyEnum::Variant1));
+    assert_eq!(
+        MyEnum::try_from_str_const("Variant2"),
+        Some(MyEnum::Variant2)
+    );
+    assert_eq!(MyEnum::try_from_str_const("variant2"), None);
+    assert_eq!(
+        ForeignEnum::try_from_str_const("ALPHA"),
+        Some(ForeignEnum::Alpha)
+    );
+    assert_eq!(
+        ForeignEnum::try_from_str_const("B"),
+        Some(ForeignEnum::Beta)
+    );
+    assert_eq!(Shape::try_from_str_const("hexagon"), Some(Shape::Unknown));
+    assert_eq!(SkipEnum::try_from_str_const("internal"), None);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn test_into_static_str() {
+    let value: &'static str = MyEnum::Variant2.into();
+    assert_eq!(value, "Variant2");
+    let value: &'static str = (&MyEnum::Variant1).into();
+    assert_eq!(value, "Variant1");
+}
+
+#[test]
+fn test_as_c_str() {
+    assert_eq!(MyEnum::Variant1.as_c_str(), c"Variant1");
+    assert_eq!(
+        RenamedEnum::ALL_VARIANTS[0].as_c_str().to_bytes(),
+        RenamedEnum::ALL_VALUES[0].as_bytes()
+    );
+}
+
+#[test]
+fn test_try_from_bytes() {
+    assert_eq!(
+        MyEnum::try_from(b"variant1".as_slice()).unwrap(),
+        MyEnum::Variant1
+    );
+    assert_eq!(
+        MyEnum::try_from(b"Variant2".to_vec()).unwrap(),
+        MyEnum::Variant2
+    );
+    assert!(matches!(
+        MyEnum::try_from(b"\xff".as_slice()),
+        Err(str_enum::Utf8EnumError::Utf8(_))
+    ));
+    assert!(matches!(
+        MyEnum::try_from(b"nope".to_vec()),
+        Err(str_enum::Utf8EnumError::InvalidVariant(MyError))
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_try_from_paths() {
+    use std::{
+        ffi::OsString,
+        path::{Path, PathBuf},
+    };
+
+    assert_eq!(
+        MyEnum::try_from(Path::new("variant1")).unwrap(),
+        MyEnum::Variant1
+    );
+    assert_eq!(
+        MyEnum::try_from(PathBuf::from("Variant2")).unwrap(),
+        MyEnum::Variant2
+    );
+    assert_eq!(
+        MyEnum::try_from(OsString::from("Variant1")).unwrap(),
+        MyEnum::Variant1
+    );
+    assert!(MyEnum::try_from(PathBuf::from("plugins/Variant1")).is_err());
+}
+
+#[test]
+fn test_try_from_cow() {
+    use std::borrow::Cow;
+
+    assert_eq!(
+        MyEnum::try_from(Cow::Borrowed("Variant2")).unwrap(),
+        MyEnum::Variant2
+    );
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn test_into_os_string_and_path_buf() {
+    use std::{ffi::OsString, path::PathBuf};
+
+    let os_string: OsString = MyEnum::Variant1.into();
+    assert_eq!(os_string, "Variant1");
+    let path: PathBuf = (&MyEnum::Variant2).into();
+    assert_eq!(
+        PathBuf::from("plugins").join(path),
+        PathBuf::from("plugins/Variant2")
+    );
+}
+
+str_enum! {
+    #[error_type(CapturingError, capture_input)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u8)]
+    enum Capturing {
+        First => "first",
+        Second => "second",
+    }
+}
+
+#[test]
+fn test_error_capture_input() {
+    let err = "thrid".parse::<Capturing>().unwrap_err();
+    assert_eq!(err.input(), "thrid");
+    assert_eq!(
+        err.to_string(),
+        "invalid Capturing: expected one of \"first\" or \"second\", found \"thrid\""
+    );
+    assert_eq!(Capturing::try_from(9u8).unwrap_err().0, "9");
+    assert_eq!(
+        MyError.to_string(),
+        "invalid MyEnum: expected one of \"Variant1\" or \"Variant2\""
+    );
+}
+
+#[test]
+fn test_suggest() {
+    assert_eq!(Capturing::suggest("secnd"), Some("second"));
+    assert_eq!(Capturing::suggest("zzzzzz"), None);
+    assert_eq!(MyEnum::suggest("varient1"), Some("variant1"));
+    assert_eq!(SkipEnum::suggest("Internl"), None);
+    assert_eq!(
+        "secnd".parse::<Capturing>().unwrap_err().to_string(),
+        "invalid Capturing: expected one of \"first\" or \"second\", found \"secnd\", did you mean \"second\"?"
+    );
+}
+
+str_enum! {
+    #[error_type(OrderedError, derive(PartialOrd, Ord))]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Ordered {
+        Low => "low",
+    }
+}
+
+#[test]
+fn test_error_derives() {
+    use std::collections::HashSet;
+
+    assert_eq!("nope".parse::<MyEnum>(), Err(MyError));
+    assert_eq!(
+        "nope".parse::<Capturing>(),
+        Err(CapturingError("nope".into()))
+    );
+    let errors: HashSet<CapturingError> = ["a", "b", "a"]
+        .into_iter()
+        .filter_map(|s| s.parse::<Capturing>().err())
+        .collect();
+    assert_eq!(errors.len(), 2);
+    assert!(OrderedError <= OrderedError);
+    assert_eq!(Ordered::try_from_str("low"), Some(Ordered::Low));
+}
+
+str_enum! {
+    #[error_type(LevelError, message("unknown log level, valid levels are {values}"))]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Level {
+        Info => "info",
+        Warn => "warn",
+    }
+}
+
+#[test]
+fn test_error_message() {
+    assert_eq!(
+        LevelError.to_string(),
+        "unknown log level, valid levels are \"info\" or \"warn\""
+    );
+}
+
+#[test]
+fn test_utf8_enum_error_accessors() {
+    use std::error::Error;
+
+    let err = MyEnum::try_from(b"nope".as_slice()).unwrap_err();
+    assert_eq!(err.as_invalid_variant(), Some(&MyError));
+    assert!(err.as_utf8().is_none());
+    assert!(err.source().is_some());
+    assert_eq!(err.map_invalid(|_| "mapped").into_inner(), Ok("mapped"));
+
+    let err = MyEnum::try_from(b"\xff".as_slice()).unwrap_err();
+    let utf8_error = *err.as_utf8().unwrap();
+    let err: str_enum::Utf8EnumError<MyError> = utf8_error.into();
+    assert_eq!(err.as_utf8(), Some(&utf8_error));
+    assert_eq!(err.into_inner(), Err(utf8_error));
+}
+
+#[test]
+fn test_option_parsing_without_error_type() {
+    #[cfg(feature = "std")]
+    {
+        use std::ffi::OsStr;
+
+        assert_eq!(
+            Protocol::try_from_os_str(OsStr::new("1")),
+            Some(Protocol::V1)
+        );
+        assert_eq!(Protocol::try_from_os_str(OsStr::new("v9")), None);
+    }
+    assert_eq!(Protocol::try_from_bytes(b"v0"), Some(Protocol::V0));
+    assert_eq!(Protocol::try_from_bytes(b"\xff"), None);
+}
+
+#[test]
+fn test_aliases() {
+    assert_eq!(MyEnum::Variant1.aliases(), &["variant1"]);
+    assert!(MyEnum::Variant2.aliases().is_empty());
+    assert_eq!(ScopedEnum::ALL_ALIASES.len(), ScopedEnum::ALL_VALUES.len());
+    assert_eq!(Fruit::ALL_ALIASES, &[&[][..], &["plantain"], &[]]);
+}
+
+#[test]
+fn test_all_pairs() {
+    assert_eq!(
+        MyEnum::ALL_PAIRS,
+        &[
+            ("Variant1", MyEnum::Variant1),
+            ("Variant2", MyEnum::Variant2)
+        ]
+    );
+}
+
+#[test]
+fn test_canonicalize() {
+    assert_eq!(MyEnum::canonicalize("variant1"), Some("Variant1"));
+    assert_eq!(MyEnum::canonicalize("Variant2"), Some("Variant2"));
+    assert_eq!(MyEnum::canonicalize("nope"), None);
+}
+
+str_enum! {
+    #[parse(sorted, case_insensitive)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Mime {
+        Json => "application/json"("json"),
+        Html => "text/html"(serde "html"),
+        Css => "text/css",
+        Plain => "text/plain"(parse "txt", "text"),
+    }
+}
+
+#[test]
+fn test_sorted_parse() {
+    assert_eq!(Mime::try_from_str("text/css"), Some(Mime::Css));
+    assert_eq!(Mime::try_from_str("json"), Some(Mime::Json));
+    assert_eq!(Mime::try_from_str("txt"), Some(Mime::Plain));
+    assert_eq!(Mime::try_from_str("html"), None);
+    assert_eq!(
+        Mime::try_from_str_scoped("html", str_enum::AliasScope::Serde),
+        Some(Mime::Html)
+    );
+    assert_eq!(Mime::try_from_str("TEXT/HTML"), Some(Mime::Html));
+    assert_eq!(Mime::match_detailed("text"), Some((Mime::Plain, "text")));
+    assert_eq!(Mime::try_from_str("text/"), None);
+}
+
+str_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Header {
+        Accept => "accept",
+        Age => "age"(parse "AGE"),
+        Allow => "allow",
+        Host => "host",
+        Via => "via",
+        Vary => "vary",
+    }
+}
+
+#[test]
+fn test_bucketed_parse() {
+    for (value, variant) in Header::ALL_PAIRS {
+        assert_eq!(Header::try_from_str(value).as_ref(), Some(variant));
+    }
+    assert_eq!(Header::try_from_str("AGE"), Some(Header::Age));
+    assert_eq!(Header::try_from_str("ag"), None);
+    assert_eq!(Header::try_from_str("vie"), None);
+    assert_eq!(Header::try_from_str(""), None);
+}
+
+#[test]
+fn test_ignore_ascii_case_helpers() {
+    assert!(MyEnum::Variant1.eq_str_ignore_ascii_case("VARIANT1"));
+    assert!(!MyEnum::Variant1.eq_str_ignore_ascii_case("Variant2"));
+    assert_eq!(MyEnum::try_from_str("VARIANT2"), None);
+    assert_eq!(
+        MyEnum::try_from_str_ignore_ascii_case("VARIANT2"),
+        Some(MyEnum::Variant2)
+    );
+    assert_eq!(MyEnum::try_from_str_ignore_ascii_case("nope"), None);
+    assert_eq!(
+        Shape::try_from_str_ignore_ascii_case("CIRCLE"),
+        Some(Shape::Circle)
+    );
+    assert_eq!(
+        Shape::try_from_str_ignore_ascii_case("triangle"),
+        Some(Shape::Unknown)
+    );
+}
+
+str_enum! {
+    #[parse(trim, case_insensitive)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Encoding {
+        Gzip => "gzip"("x-gzip"),
+        Identity => "identity",
+    }
+}
+
+#[test]
+fn test_trim() {
+    assert_eq!(Encoding::try_from_str(" gzip\t"), Some(Encoding::Gzip));
+    assert_eq!(Encoding::try_from_str(" X-GZIP "), Some(Encoding::Gzip));
+    assert_eq!(
+        Encoding::match_detailed("identity\n"),
+        Some((Encoding::Identity, "identity"))
+    );
+    assert_eq!(Encoding::try_from_str("   "), None);
+    assert_eq!(Encoding::try_from_str_const(" gzip "), Some(Encoding::Gzip));
+    assert_eq!(MyEnum::try_from_str(" Variant1 "), None);
+    assert_eq!(
+        MyEnum::try_from_str_trimmed(" Variant1 "),
+        Some(MyEnum::Variant1)
+    );
+}
+
+str_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Command {
+        Get => "GET",
+        GetAll => "GETALL",
+        Set => "SET"("set"),
+    }
+}
+
+#[test]
+fn test_match_prefix() {
+    assert_eq!(
+        Command::match_prefix("GET /path"),
+        Some((Command::Get, " /path"))
+    );
+    assert_eq!(
+        Command::match_prefix("GETALL keys"),
+        Some((Command::GetAll, " keys"))
+    );
+    assert_eq!(Command::match_prefix("set x"), Some((Command::Set, " x")));
+    assert_eq!(Command::match_prefix("SET"), Some((Command::Set, "")));
+    assert_eq!(Command::match_prefix("DEL x"), None);
+}
+
+#[test]
+fn test_find_all() {
+    let found: Vec<_> = Command::find_all("GETALL, then SET é GET").collect();
+    assert_eq!(
+        found,
+        [
+            (0, Command::Get),
+            (0, Command::GetAll),
+            (13, Command::Set),
+            (20, Command::Get)
+        ]
+    );
+    assert_eq!(Command::find_all("set get").count(), 0);
+}
+
+#[test]
+fn test_parse_list() {
+    assert_eq!(
+        Command::parse_list("GET, set ,GETALL", ','),
+        Ok(vec![Command::Get, Command::Set, Command::GetAll])
+    );
+    assert_eq!(Command::parse_list("  ", ','), Ok(vec![]));
+    let error = Command::parse_list("GET;DEL ;SET", ';').unwrap_err();
+    assert_eq!(
+        error,
+        str_enum::ParseListError {
+            index: 1,
+            value: "DEL".to_string()
+        }
+    );
+    assert_eq!(error.to_string(), "element 1: invalid value \"DEL\"");
+    assert!(Command::parse_list("GET,,SET", ',').is_err());
+}
+
+str_enum! {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Version {
+        V1 => "v1.0",
+        V1Beta => "v1.0-beta+1",
+        Any => "*",
+    }
+}
+
+#[test]
+fn test_regex_pattern() {
+    assert_eq!(
+        Mime::REGEX_PATTERN,
+        "application/json|text/plain|text/html|text/css"
+    );
+    assert_eq!(Version::REGEX_PATTERN, r"v1\.0\-beta\+1|v1\.0|\*");
+}
+
+#[test]
+fn test_expected_list() {
+    assert_eq!(Version::EXPECTED_LIST, "\"v1.0\", \"v1.0-beta+1\" or \"*\"");
+    assert_eq!(MyEnum::EXPECTED_LIST, "\"Variant1\" or \"Variant2\"");
+}
+
+str_enum! {
+    #[error_type(NothingError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[parse(case_insensitive)]
+    enum Nothing {}
+}
+
+#[test]
+fn test_empty_enum() {
+    assert_eq!(Nothing::NUM_VARIANTS, 0);
+    assert!(Nothing::ALL_VALUES.is_empty());
+    assert_eq!(Nothing::EXPECTED_LIST, "");
+    assert_eq!(Nothing::try_from_str("anything"), None);
+    assert_eq!(Nothing::try_from_str_const("anything"), None);
+    assert_eq!(Nothing::match_prefix("anything"), None);
+    assert_eq!(Nothing::find_all("anything").count(), 0);
+    assert!("anything".parse::<Nothing>().is_err());
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(C)]
+    enum CLayout {
+        First => "first",
+        Second => "second",
+    }
+}
+
+str_enum! {
+    #[error_type(TaggedError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u8, align(4))]
+    enum Tagged {
+        First = 3 => "first",
+        Second => "second",
+    }
+}
+
+#[test]
+fn test_layout_repr() {
+    assert_eq!(
+        std::mem::size_of::<CLayout>(),
+        std::mem::size_of::<std::ffi::c_int>()
+    );
+    assert_eq!(CLayout::try_from_str("second"), Some(CLayout::Second));
+    assert_eq!(std::mem::align_of::<Tagged>(), 4);
+    assert_eq!(u8::from(Tagged::Second), 4);
+    assert_eq!(Tagged::from_repr(3), Some(Tagged::First));
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[skip_impls(ToSocketAddrs, Add, Index, FromIterator)]
+    enum Direction {
+        Up => "up",
+        Down => "down",
+    }
+}
+
+impl std::ops::Index<Direction> for Direction {
+    type Output = i32;
+
+    fn index(&self, index: Direction) -> &i32 {
+        if *self == index { &1 } else { &-1 }
+    }
+}
+
+str_enum! {
+    #[error_type(BareError)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[skip_impls(Add, AsRef, Borrow, Display, Extend, From, FromIterator, Hash, Index, IntoStaticStr, PartialEq, PartialOrd, ToSocketAddrs)]
+    #[catch_all(MaybeBare)]
+    #[str_type(BareStr)]
+    enum Bare {
+        First => "first",
+    }
+}
+
+// `#[cmp]` and `#[enum_set]` ahead of `#[skip_impls]` must stay out of its list
+str_enum! {
+    #[cmp(str)]
+    #[enum_set(PebbleSet)]
+    #[derive(Debug, Clone, Copy)]
+    #[skip_impls(Add, Index)]
+    enum Pebble {
+        Smooth => "smooth",
+        Rough => "rough",
+    }
+}
+
+#[test]
+fn test_skip_impls() {
+    assert_eq!(Direction::Up[Direction::Up], 1);
+    assert_eq!(Direction::Up[Direction::Down], -1);
+    assert_eq!(Direction::Down.to_string(), "down");
+    assert_eq!("first".parse::<Bare>(), Ok(Bare::First));
+    assert_eq!(MaybeBare::new("first").known(), Some(&Bare::First));
+    assert!(Pebble::Rough < Pebble::Smooth);
+    assert!(PebbleSet::from(Pebble::Rough).contains(&Pebble::Rough));
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy)]
+    #[cmp(str)]
+    enum Berry {
+        Raspberry => "raspberry",
+        Blueberry => "blueberry",
+        Cherry => "cherry",
+    }
+}
+
+#[test]
+fn test_cmp_str() {
+    assert!(Berry::Blueberry < Berry::Raspberry);
+    assert_eq!(Berry::Cherry, Berry::Cherry);
+    assert_ne!(Berry::Cherry, Berry::Raspberry);
+
+    let map: std::collections::BTreeMap<Berry, u8> = [
+        (Berry::Raspberry, 1),
+        (Berry::Blueberry, 2),
+        (Berry::Cherry, 3),
+    ]
+    .into();
+    assert_eq!(map.get("cherry"), Some(&3));
+    assert_eq!(map.get("blueberry"), Some(&2));
+    assert_eq!(
+        map.keys().copied().collect::<Vec<Berry>>(),
+        [Berry::Blueberry, Berry::Cherry, Berry::Raspberry]
+    );
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[enum_map(PlanetMap)]
+    #[enum_set(PlanetSet)]
+    enum Planet {
+        Venus = 2 => "venus",
+        Earth = 0 => "earth",
+        Mars = 1 => "mars",
+    }
+}
+
+#[test]
+fn test_enum_map() {
+    let mut visits = PlanetMap::<u32>::default();
+    visits[Planet::Mars] += 2;
+    *visits.get_mut(&Planet::Venus) += 1;
+    assert_eq!(visits[Planet::Mars], 2);
+    assert_eq!(visits.get(&Planet::Earth), &0);
+    assert_eq!(
+        visits.iter().collect::<Vec<_>>(),
+        [(Planet::Venus, &1), (Planet::Earth, &0), (Planet::Mars, &2)]
+    );
+    assert_eq!(visits.into_array(), [1, 0, 2]);
+    assert_eq!(
+        format!("{visits:?}"),
+        r#"{"venus": 1, "earth": 0, "mars": 2}"#
+    );
+}
+
+#[test]
+fn test_enum_set() {
+    let mut set = PlanetSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(Planet::Mars));
+    assert!(!set.insert(Planet::Mars));
+    set |= Planet::Venus.into();
+    assert!(set.contains(&Planet::Venus));
+    assert!(!set.contains(&Planet::Earth));
+    assert_eq!(set.len(), 2);
+    assert_eq!(
+        set.iter().collect::<Vec<Planet>>(),
+        [Planet::Venus, Planet::Mars]
+    );
+    assert_eq!(set.bits(), 0b101u8);
+
+    assert_eq!(PlanetSet::all() - set, PlanetSet::from(Planet::Earth));
+    assert_eq!(set & PlanetSet::from(Planet::Mars), Planet::Mars.into());
+    assert!(set.remove(&Planet::Mars));
+    assert!(!set.remove(&Planet::Mars));
+
+    let parsed: PlanetSet = "mars, earth".parse().unwrap();
+    assert_eq!(parsed.to_string(), "earth,mars");
+    assert_eq!(parsed.to_string().parse::<PlanetSet>(), Ok(parsed));
+    assert_eq!("".parse::<PlanetSet>(), Ok(PlanetSet::new()));
+    assert_eq!(
+        "mars,pluto".parse::<PlanetSet>().unwrap_err().to_string(),
+        "element 1: invalid value \"pluto\""
+    );
+    assert_eq!(format!("{parsed:?}"), r#"{"earth", "mars"}"#);
+}
+
+#[test]
+fn test_variant_iterators() {
+    let mut variants: str_enum::Variants<Planet> = Planet::iter();
+    assert_eq!(variants.len(), 3);
+    assert_eq!(variants.next(), Some(Planet::Venus));
+    assert_eq!(variants.next_back(), Some(Planet::Mars));
+    assert_eq!(variants.collect::<Vec<Planet>>(), [Planet::Earth]);
+
+    assert_eq!(
+        Planet::values().collect::<Vec<_>>(),
+        ["venus", "earth", "mars"]
+    );
+    assert_eq!(
+        Planet::variant_names().rev().collect::<Vec<_>>(),
+        ["Mars", "Earth", "Venus"]
+    );
+    assert_eq!(Planet::VARIANT_NAMES, ["Venus", "Earth", "Mars"]);
+}
+
+#[test]
+fn test_variant_array() {
+    const LENGTHS: [usize; Planet::NUM_VARIANTS] = {
+        let mut lengths = [0; Planet::NUM_VARIANTS];
+        let mut idx = 0;
+        while idx < Planet::NUM_VARIANTS {
+            lengths[idx] = Planet::VARIANT_ARRAY[idx].as_str().len();
+            idx += 1;
+        }
+        lengths
+    };
+    assert_eq!(LENGTHS, [5, 5, 4]);
+    assert_eq!(
+        Planet::VARIANT_ARRAY.map(|planet| planet.as_str()),
+        ["venus", "earth", "mars"]
+    );
+    assert_eq!(Planet::ALL_VARIANTS, Planet::VARIANT_ARRAY);
+}
+
+#[test]
+fn test_index() {
+    const EARTH: usize = Planet::Earth.index();
+    assert_eq!(EARTH, 1);
+    assert_eq!(Planet::Venus.index(), 0);
+    assert_eq!(Planet::from_index(2), Some(Planet::Mars));
+    assert_eq!(Planet::from_index(3), None);
+    for planet in Planet::iter() {
+        assert_eq!(Planet::from_index(planet.index()), Some(planet));
+    }
+    assert_eq!(Nothing::from_index(0), None);
+}
+
+#[test]
+fn test_cycling() {
+    const AFTER_MARS: Planet = Planet::Mars.next();
+    assert_eq!(AFTER_MARS, Planet::Venus);
+    assert_eq!(Planet::Venus.next(), Planet::Earth);
+    assert_eq!(Planet::Venus.prev(), Planet::Mars);
+    assert_eq!(Planet::Mars.prev(), Planet::Earth);
+    assert_eq!(Planet::Earth.checked_next(), Some(Planet::Mars));
+    assert_eq!(Planet::Mars.checked_next(), None);
+    assert_eq!(Planet::Earth.checked_prev(), Some(Planet::Venus));
+    assert_eq!(Planet::Venus.checked_prev(), None);
+}
+
+#[test]
+fn test_str_len_bounds() {
+    const BUF: [u8; Planet::MAX_STR_LEN] = [0; Planet::MAX_STR_LEN];
+    assert_eq!(BUF.len(), 5);
+    assert_eq!(Planet::MIN_STR_LEN, 4);
+    assert_eq!(Nothing::MAX_STR_LEN, 0);
+    assert_eq!(Nothing::MIN_STR_LEN, 0);
+}
+
+#[test]
+fn test_variant_macro() {
+    assert_eq!(str_enum::variant!(Planet, "earth"), Planet::Earth);
+    const MARS: Planet = str_enum::variant!(Planet, "mars");
+    assert_eq!(MARS, Planet::Mars);
+    assert_eq!(str_enum::variant!(MyEnum, "Variant2"), MyEnum::Variant2);
+}
+
+#[test]
+fn test_pattern_macro() {
+    fn classify(s: &str) -> &'static str {
+        match s {
+            MyEnum!(pattern Variant1) => "first",
+            MyEnum!(pattern) => "other",
+            _ => "unknown",
+        }
+    }
+    assert_eq!(classify("Variant1"), "first");
+    assert_eq!(classify("variant1"), "first");
+    assert_eq!(classify("Variant2"), "other");
+    assert_eq!(classify("nope"), "unknown");
+    assert!(matches!("mars", Planet!(pattern)));
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Theme {
+        Light => "light",
+        /// Follows the system setting
+        #[parse(skip)]
+        #[default]
+        System => "system",
+        Dark => "dark",
+    }
+}
+
+#[test]
+fn test_default_variant() {
+    assert_eq!(Theme::default(), Theme::System);
+    assert_eq!(
+        Theme::System.documentation(),
+        Some("Follows the system setting")
+    );
+    assert_eq!(Theme::try_from_str("system"), None);
+}
+
+#[test]
+fn test_pattern_macro_matches_parsing() {
+    assert!(matches!("v1", ScopedEnum!(pattern)));
+    assert!(matches!("variant1", ScopedEnum!(pattern Variant1)));
+    assert!(!matches!("variant_1", ScopedEnum!(pattern)));
+    assert!(matches!("dark", Theme!(pattern)));
+    assert!(!matches!("system", Theme!(pattern)));
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[allow(non_camel_case_types)]
+    enum Keyword {
+        r#type,
+        r#match("match_"),
+        Plain,
+    }
+}
+
+#[test]
+fn test_raw_identifier_values() {
+    assert_eq!(Keyword::ALL_VALUES, &["type", "match", "Plain"]);
+    assert_eq!(Keyword::r#type.as_str(), "type");
+    assert_eq!(Keyword::try_from_str("type"), Some(Keyword::r#type));
+    assert_eq!(Keyword::try_from_str("r#type"), None);
+    assert!(matches!("type", Keyword!(pattern r#type)));
+    assert!(matches!("match_", Keyword!(pattern r#match)));
+    assert!(matches!("Plain", Keyword!(pattern)));
+    assert!(!matches!("r#type", Keyword!(pattern)));
+}
+
+#[test]
+fn test_pattern_macro_rename_all() {
+    assert!(matches!("http-server", RenamedEnum!(pattern HttpServer)));
+    assert!(matches!("server", RenamedEnum!(pattern HttpServer)));
+    assert!(matches!("proxy", RenamedEnum!(pattern)));
+    assert!(!matches!("Proxy", RenamedEnum!(pattern)));
+    assert!(matches!("TYPE", ScreamingEnum!(pattern r#Type)));
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Scheme {
+        Https => "https",
+        #[deprecated(note = "use `Scheme::Https`")]
+        Http => "http",
+        #[deprecated]
+        #[default]
+        Gopher => "gopher",
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_variant() {
+    assert!(!Scheme::Https.is_deprecated());
+    assert!(Scheme::Http.is_deprecated());
+    assert!(Scheme::Gopher.is_deprecated());
+    assert_eq!(Scheme::try_from_str("http"), Some(Scheme::Http));
+    assert_eq!(Scheme::default(), Scheme::Gopher);
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[enum_set(TargetSet)]
+    enum Target {
+        Native => "native",
+        #[cfg(any())]
+        /// Never compiled in
+        Missing => "missing-target",
+        /// Always compiled in
+        #[cfg(all())]
+        #[cfg(not(any()))]
+        Wasm => "wasm"("wasm32"),
+    }
+}
+
+#[test]
+fn test_cfg_variants() {
+    assert_eq!(Target::ALL_VALUES, &["native", "wasm"]);
+    assert_eq!(Target::NUM_VARIANTS, 2);
+    assert_eq!(Target::MAX_STR_LEN, 6);
+    assert_eq!(Target::try_from_str("wasm32"), Some(Target::Wasm));
+    assert_eq!(Target::try_from_str("missing-target"), None);
+    assert_eq!(Target::Wasm.documentation(), Some("Always compiled in"));
+    assert_eq!(TargetSet::all().len(), 2);
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Region {
+        UsEast => "us_east"(display parse "US East", parse "use1"),
+        EuWest => "eu_west"(display "EU West"),
+        Local => "local",
+    }
+}
+
+#[test]
+fn test_display_form() {
+    assert_eq!(Region::UsEast.to_string(), "US East");
+    assert_eq!(format!("{:>9}", Region::EuWest), "  EU West");
+    assert_eq!(Region::Local.display_str(), "local");
+    assert_eq!(Region::UsEast.as_str(), "us_east");
+    assert_eq!(Region::try_from_str("US East"), Some(Region::UsEast));
+    assert_eq!(Region::try_from_str("use1"), Some(Region::UsEast));
+    for region in Region::iter() {
+        assert_eq!(Region::try_from_str(region.display_str()), Some(region));
+    }
+    let inputs: [&[u8]; 4] = [b"us_east", b"US East", b"eu_west", b"EU West"];
+    for data in inputs {
+        str_enum::fuzz_check!(Region, data);
+    }
+}
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[constant(code: u16, from_code)]
+    enum HttpStatus {
+        Ok => "ok": 200,
+        NotFound => "not_found": 404u16 ("missing"),
+        Teapot: 418,
+    }
+}
+
+#[test]
+fn test_variant_constant() {
+    const CODE: u16 = HttpStatus::NotFound.code();
+    assert_eq!(CODE, 404);
+    assert_eq!(HttpStatus::Teapot.code(), 418);
+    assert_eq!(HttpStatus::from_code(200), Some(HttpStatus::Ok));
+    assert_eq!(HttpStatus::from_code(500), None);
+    assert_eq!(
+        HttpStatus::try_from_str("missing"),
+        Some(HttpStatus::NotFound)
+    );
 }
@@ -0,0 +1,26 @@
+#![cfg(feature = "utoipa")]
+
+use serde_json::json;
+use str_enum::{
+    str_enum,
+    utoipa::{PartialSchema, ToSchema},
+};
+
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum MyEnum {
+        Variant1 => "Variant1"("variant1"),
+        Variant2 => "Variant2",
+        #[parse(skip)] Internal => "Internal",
+    }
+}
+
+#[test]
+fn test_to_schema() {
+    assert_eq!(MyEnum::name(), "MyEnum");
+    let schema = serde_json::to_value(MyEnum::schema()).unwrap();
+    assert_eq!(
+        schema,
+        json!({ "type": "string", "enum": ["Variant1", "Variant2"] })
+    );
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "verification")]
+
+use str_enum::str_enum;
+
+// the harnesses only exist under `cargo kani`, this makes sure enums still build with the feature on
+str_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u8)]
+    enum VerifiedEnum {
+        Variant1 = 2 => "Variant1"("variant1", parse "v1"),
+        #[parse(skip)]
+        Variant2 => "Variant2"(serde "v1"),
+    }
+}
+
+#[test]
+fn test_verification_builds() {
+    assert_eq!(
+        VerifiedEnum::try_from_str("v1"),
+        Some(VerifiedEnum::Variant1)
+    );
+    assert_eq!(VerifiedEnum::try_from_str("Variant2"), None);
+}